@@ -0,0 +1,225 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use bench::{bench_part, generate};
+
+// Every part of every day, on the real input and on generated ones a few
+// times bigger, with parse and solve timed separately.
+//
+// Usage: cargo bench -p bench                              # everything
+//        cargo bench -p bench -- day-06                    # one day
+//        cargo bench -p bench -- --save-baseline main      # save a baseline
+//        cargo bench -p bench -- --baseline main           # compare with it
+//
+// Save a baseline before a change and compare with it after to see what got
+// slower. Reports end up in target/criterion.
+
+const SEED: u64 = 2023;
+
+// The real input, then the generated ones named by their size
+fn inputs<'a>(real: &'a str, generated: &'a [(usize, String)]) -> Vec<(String, &'a str)> {
+    let mut inputs = vec![("real".to_string(), real)];
+
+    for (size, input) in generated {
+        inputs.push((format!("generated-{}", size), input.as_str()));
+    }
+
+    inputs
+}
+
+fn sized(sizes: &[usize], generate: impl Fn(usize) -> String) -> Vec<(usize, String)> {
+    sizes.iter().map(|&size| (size, generate(size))).collect()
+}
+
+fn day_01(c: &mut Criterion) {
+    let generated = sized(&[1_000, 10_000, 100_000], |size| {
+        generate::calibration_document(size, SEED)
+    });
+    let inputs = inputs(include_str!("../../day-01/src/bin/input.txt"), &generated);
+
+    bench_part(c, "day-01/part-1", &inputs, day_01::part1::parse, |lines| {
+        day_01::part1::solve(lines)
+    });
+    bench_part(c, "day-01/part-2", &inputs, day_01::part2::parse, |lines| {
+        day_01::part2::solve(lines)
+    });
+}
+
+fn day_02(c: &mut Criterion) {
+    let generated = sized(&[100, 1_000, 10_000], |size| generate::games(size, SEED));
+    let inputs = inputs(include_str!("../../day-02/src/bin/input.txt"), &generated);
+
+    bench_part(
+        c,
+        "day-02/part-1",
+        &inputs,
+        |input| day_02::part1::parse(input).expect("Games should be valid"),
+        |games| day_02::part1::solve(games),
+    );
+    bench_part(
+        c,
+        "day-02/part-2",
+        &inputs,
+        |input| day_02::part2::parse(input).expect("Games should be valid"),
+        |games| day_02::part2::solve(games),
+    );
+}
+
+fn day_03(c: &mut Criterion) {
+    let generated = sized(&[140, 500, 1_000], |size| {
+        day_03::generate::schematic(size, SEED)
+    });
+    let inputs = inputs(include_str!("../../day-03/src/bin/input.txt"), &generated);
+
+    bench_part(
+        c,
+        "day-03/part-1",
+        &inputs,
+        |input| day_03::part1::parse(input).expect("Schematic should be valid"),
+        |schematic| day_03::part1::solve(schematic).expect("Total should fit in a u64"),
+    );
+    bench_part(
+        c,
+        "day-03/part-2",
+        &inputs,
+        |input| day_03::part2::parse(input).expect("Schematic should be valid"),
+        |schematic| day_03::part2::solve(schematic).expect("Total should fit in a u64"),
+    );
+}
+
+fn day_04(c: &mut Criterion) {
+    let generated = sized(&[200, 2_000, 20_000], |size| {
+        generate::scratchcards(size, SEED)
+    });
+    let inputs = inputs(include_str!("../../day-04/src/bin/input.txt"), &generated);
+
+    bench_part(
+        c,
+        "day-04/part-1",
+        &inputs,
+        |input| day_04::part1::parse(input).expect("Cards should be valid"),
+        |cards| day_04::part1::solve(cards),
+    );
+    bench_part(
+        c,
+        "day-04/part-2",
+        &inputs,
+        |input| day_04::part2::parse(input).expect("Cards should be valid"),
+        |cards| day_04::part2::solve(cards),
+    );
+}
+
+fn day_05(c: &mut Criterion) {
+    let generated = sized(&[10, 100, 1_000], |size| generate::almanac(size, SEED));
+    let inputs = inputs(include_str!("../../day-05/src/bin/input.txt"), &generated);
+
+    bench_part(
+        c,
+        "day-05/part-1",
+        &inputs,
+        day_05::part1::parse,
+        day_05::part1::solve,
+    );
+    bench_part(
+        c,
+        "day-05/part-2",
+        &inputs,
+        day_05::part2::parse,
+        day_05::part2::solve,
+    );
+}
+
+fn day_06(c: &mut Criterion) {
+    let real = include_str!("../../day-06/src/bin/input.txt");
+
+    // Part 1 has a few short races, so it scales with how long they are
+    let generated = sized(&[100, 10_000, 1_000_000], |size| {
+        generate::races(4, size as u64, SEED)
+    });
+    bench_part(
+        c,
+        "day-06/part-1",
+        &inputs(real, &generated),
+        day_06::part1::parse,
+        |races| day_06::part1::solve(races),
+    );
+
+    // Part 2 is one long race. Every winning hold is kept in a Vec, so these
+    // stay below the real input to keep memory down.
+    let generated = sized(&[100_000, 1_000_000, 10_000_000], |size| {
+        generate::races(1, size as u64, SEED)
+    });
+    bench_part(
+        c,
+        "day-06/part-2",
+        &inputs(real, &generated),
+        day_06::part2::parse,
+        day_06::part2::solve,
+    );
+}
+
+fn day_07(c: &mut Criterion) {
+    let generated = sized(&[1_000, 10_000, 100_000], |size| {
+        generate::hands(size, SEED)
+    });
+    let inputs = inputs(include_str!("../../day-07/src/bin/input.txt"), &generated);
+
+    bench_part(c, "day-07/part-1", &inputs, day_07::part1::parse, |hands| {
+        day_07::part1::solve(hands)
+    });
+    bench_part(c, "day-07/part-2", &inputs, day_07::part2::parse, |hands| {
+        day_07::part2::solve(hands)
+    });
+}
+
+fn day_08(c: &mut Criterion) {
+    let real = include_str!("../../day-08/src/bin/input.txt");
+    let generated = sized(&[50, 200, 800], |size| generate::network(size, SEED));
+
+    bench_part(
+        c,
+        "day-08/part-1",
+        &inputs(real, &generated),
+        day_08::part1::parse,
+        day_08::part1::solve,
+    );
+
+    // Part 2 only looks one lap further ahead each time round, so it doesn't
+    // finish on the real input. The generated networks line up after about a
+    // hundred laps.
+    let generated = generated
+        .iter()
+        .map(|(size, input)| (format!("generated-{}", size), input.as_str()))
+        .collect::<Vec<(String, &str)>>();
+    bench_part(
+        c,
+        "day-08/part-2",
+        &generated,
+        day_08::part2::parse,
+        day_08::part2::solve,
+    );
+}
+
+fn day_09(c: &mut Criterion) {
+    let generated = sized(&[200, 2_000, 20_000], |size| {
+        generate::histories(size, SEED)
+    });
+    let inputs = inputs(include_str!("../../day-09/src/bin/input.txt"), &generated);
+
+    bench_part(
+        c,
+        "day-09/part-1",
+        &inputs,
+        day_09::part1::parse,
+        |sequences| day_09::part1::solve(sequences),
+    );
+    bench_part(
+        c,
+        "day-09/part-2",
+        &inputs,
+        day_09::part2::parse,
+        |sequences| day_09::part2::solve(sequences),
+    );
+}
+
+criterion_group!(benches, day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09);
+criterion_main!(benches);
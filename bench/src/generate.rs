@@ -0,0 +1,375 @@
+use std::collections::HashSet;
+
+use rng::Rng;
+
+// Random puzzle inputs for benchmarking, scaled up past the size of the real
+// ones. The same seed always gives the same input, and the generators only
+// ever make inputs the solutions accept.
+
+const NUMBER_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// Day 1, lines of letters, digits and number words. Every line has at least
+// one digit, so part 2 doesn't reject it.
+pub fn calibration_document(lines: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+
+    for _ in 0..lines {
+        let mut pieces = Vec::new();
+
+        for _ in 0..rng.between(1, 8) {
+            let piece = match rng.next(4) {
+                0 => rng.next(10).to_string(),
+                1 => NUMBER_WORDS[rng.next(9) as usize].to_string(),
+                _ => (0..rng.between(1, 4))
+                    .map(|_| (b'a' + rng.next(26) as u8) as char)
+                    .collect(),
+            };
+            pieces.push(piece);
+        }
+
+        let at = rng.next(pieces.len() as u64 + 1) as usize;
+        pieces.insert(at, rng.next(10).to_string());
+
+        output.push_str(&pieces.concat());
+        output.push('\n');
+    }
+
+    output
+}
+
+// Day 2, games of one to six hands of red, green and blue cubes
+pub fn games(count: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+
+    for id in 1..=count {
+        let hands = (0..rng.between(1, 6))
+            .map(|_| {
+                let mut colours = vec!["red", "green", "blue"];
+                rng.shuffle(&mut colours);
+                colours.truncate(rng.between(1, 3) as usize);
+
+                colours
+                    .iter()
+                    .map(|colour| format!("{} {}", rng.between(1, 20), colour))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .collect::<Vec<String>>()
+            .join("; ");
+
+        output.push_str(&format!("Game {}: {}\n", id, hands));
+    }
+
+    output
+}
+
+// Day 4, cards with 10 winning numbers and 25 numbers we have. Most cards
+// don't win anything, so the number of copies stays small, and no card wins
+// copies of cards past the end of the table.
+pub fn scratchcards(count: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+
+    for ndx in 0..count {
+        let matching = match rng.next(4) {
+            0 => rng.between(1, 4) as usize,
+            _ => 0,
+        }
+        .min(count - 1 - ndx);
+
+        let mut numbers = (1..100).collect::<Vec<u32>>();
+        rng.shuffle(&mut numbers);
+
+        let winning = &numbers[..10];
+        let mut have = numbers[10..35 - matching].to_vec();
+        have.extend(&winning[..matching]);
+        rng.shuffle(&mut have);
+
+        let list = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|number| format!("{:>2}", number))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+
+        output.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            ndx + 1,
+            list(winning),
+            list(&have)
+        ));
+    }
+
+    output
+}
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+// Day 5, `ranges` seed ranges and up to `ranges` ranges in each map. The
+// sources in a map never overlap, but there are gaps between them.
+pub fn almanac(ranges: usize, seed: u64) -> String {
+    const LIMIT: u64 = 4_000_000_000;
+
+    let mut rng = Rng::new(seed);
+
+    let seeds = (0..ranges)
+        .map(|_| format!("{} {}", rng.next(LIMIT), rng.between(1, 100_000_000)))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let maps = MAPS
+        .iter()
+        .map(|name| {
+            let mut cuts = (0..=ranges).map(|_| rng.next(LIMIT)).collect::<Vec<u64>>();
+            cuts.sort();
+            cuts.dedup();
+
+            let mut lines = Vec::new();
+
+            for cut in cuts.windows(2) {
+                if rng.next(4) != 0 {
+                    let length = cut[1] - cut[0];
+                    lines.push(format!("{} {} {}", rng.next(LIMIT), cut[0], length));
+                }
+            }
+
+            format!("{} map:\n{}", name, lines.join("\n"))
+        })
+        .collect::<Vec<String>>();
+
+    format!("seeds: {}\n\n{}\n", seeds, maps.join("\n\n"))
+}
+
+// Day 6, `count` races that each last somewhere between time / 2 and time.
+// Every record can be beaten.
+pub fn races(count: usize, time: u64, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+
+    let (times, records): (Vec<u64>, Vec<u64>) = (0..count)
+        .map(|_| {
+            let time = rng.between((time / 2).max(2), time.max(2));
+            let hold = rng.between(1, (time / 4).max(1));
+            (time, hold * (time - hold))
+        })
+        .unzip();
+
+    let line = |numbers: &[u64]| {
+        numbers
+            .iter()
+            .map(|number| format!("{:>6}", number))
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+
+    format!("Time:     {}\nDistance: {}\n", line(&times), line(&records))
+}
+
+// Day 7, `count` different hands, as two equal hands can't be ranked
+pub fn hands(count: usize, seed: u64) -> String {
+    let cards = b"23456789TJQKA";
+    assert!(count <= 13usize.pow(5), "There are only 13^5 hands");
+
+    let mut rng = Rng::new(seed);
+    let mut seen = HashSet::new();
+    let mut output = String::new();
+
+    while seen.len() < count {
+        let hand = (0..5)
+            .map(|_| cards[rng.next(13) as usize] as char)
+            .collect::<String>();
+
+        if seen.insert(hand.clone()) {
+            output.push_str(&format!("{} {}\n", hand, rng.between(1, 1000)));
+        }
+    }
+
+    output
+}
+
+// Day 8, a network shaped like the real ones. Each of four ghosts starts on
+// a node ending in A, then goes round a cycle that's 2, 3, 5 or 7 times as
+// long as the directions, with a node ending in Z at the end of it. AAA is
+// the first ghost's start and ZZZ the end of its cycle. The direction that
+// isn't followed at each node goes somewhere random.
+pub fn network(directions: usize, seed: u64) -> String {
+    const CYCLES: [usize; 4] = [2, 3, 5, 7];
+    const STARTS: [&str; 4] = ["AAA", "BBA", "CCA", "DDA"];
+    const ENDS: [&str; 4] = ["ZZZ", "BBZ", "CCZ", "DDZ"];
+
+    // Other nodes never end in A or Z, so they can't be mistaken for a start
+    // or an end
+    let letter = |n: usize| (b'A' + n as u8) as char;
+    let id = |n: usize| {
+        format!(
+            "{}{}{}",
+            letter(n / 24 / 26 % 26),
+            letter(n / 24 % 26),
+            letter(n % 24 + 1)
+        )
+    };
+    assert!(
+        directions * CYCLES.iter().sum::<usize>() <= 26 * 26 * 24,
+        "Too many nodes for three letter ids"
+    );
+
+    let mut rng = Rng::new(seed);
+    let turns = (0..directions)
+        .map(|_| if rng.next(2) == 0 { 'L' } else { 'R' })
+        .collect::<Vec<char>>();
+
+    let mut ids = 0..;
+    let cycles = CYCLES
+        .iter()
+        .zip(ENDS)
+        .map(|(multiple, end)| {
+            let mut cycle = (1..multiple * directions)
+                .map(|_| id(ids.next().unwrap()))
+                .collect::<Vec<String>>();
+            cycle.push(end.to_string());
+            cycle
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    let everywhere = cycles.concat();
+    let mut lines = Vec::new();
+
+    let mut node = |id: &str, turn: char, next: &str, rng: &mut Rng| {
+        let other = &everywhere[rng.next(everywhere.len() as u64) as usize];
+        let (left, right) = match turn {
+            'L' => (next, other.as_str()),
+            _ => (other.as_str(), next),
+        };
+        lines.push(format!("{} = ({}, {})", id, left, right));
+    };
+
+    for (start, cycle) in STARTS.iter().zip(&cycles) {
+        node(start, turns[0], &cycle[0], &mut rng);
+
+        // The node at step k is left at step k + 1
+        for (k, id) in cycle.iter().enumerate() {
+            let next = &cycle[(k + 1) % cycle.len()];
+            node(id, turns[(k + 1) % directions], next, &mut rng);
+        }
+    }
+
+    rng.shuffle(&mut lines);
+
+    format!(
+        "{}\n\n{}\n",
+        turns.iter().collect::<String>(),
+        lines.join("\n")
+    )
+}
+
+// Day 9, histories of 21 values from polynomials of degree 0 to 6
+pub fn histories(count: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut output = String::new();
+
+    for _ in 0..count {
+        let coefficients = (0..=rng.next(7))
+            .map(|_| rng.next(19) as i64 - 9)
+            .collect::<Vec<i64>>();
+
+        let values = (0..21i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                    .to_string()
+            })
+            .collect::<Vec<String>>();
+
+        output.push_str(&values.join(" "));
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calibration_document_works() {
+        let input = calibration_document(200, 1);
+        assert_eq!(input.lines().count(), 200);
+        assert!(day_01::part2::solve(&day_01::part2::parse(&input)).is_ok());
+    }
+
+    #[test]
+    fn games_works() {
+        let input = games(50, 1);
+        let games = day_02::part1::parse(&input).unwrap();
+
+        assert_eq!(games.len(), 50);
+        assert_eq!(games[49].id, 50);
+    }
+
+    #[test]
+    fn scratchcards_works() {
+        let input = scratchcards(500, 1);
+        let cards = day_04::part2::parse(&input).unwrap();
+
+        assert_eq!(cards.len(), 500);
+        assert!(day_04::part2::solve(&cards).is_ok());
+    }
+
+    #[test]
+    fn almanac_works() {
+        let input = almanac(20, 1);
+        assert_eq!(input.lines().next().unwrap().split(' ').count(), 41);
+
+        day_05::part1::solve(&day_05::part1::parse(&input));
+        day_05::part2::solve(&day_05::part2::parse(&input));
+    }
+
+    #[test]
+    fn races_works() {
+        let input = races(3, 1000, 1);
+        assert!(day_06::part1::solve(&day_06::part1::parse(&input)) > 0);
+
+        let input = races(1, 100_000, 1);
+        assert!(day_06::part2::solve(&day_06::part2::parse(&input)) > 0);
+    }
+
+    #[test]
+    fn hands_works() {
+        let input = hands(2000, 1);
+        let hands = day_07::part1::parse(&input);
+
+        assert_eq!(hands.len(), 2000);
+        day_07::part1::solve(&hands);
+        day_07::part2::solve(&day_07::part2::parse(&input));
+    }
+
+    #[test]
+    fn network_works() {
+        let input = network(10, 1);
+
+        assert_eq!(day_08::part1::solve(&day_08::part1::parse(&input)), Ok(20));
+        assert_eq!(day_08::part2::solve(&day_08::part2::parse(&input)), 2100);
+    }
+
+    #[test]
+    fn histories_works() {
+        let input = histories(100, 1);
+        assert!(day_09::part1::solve(&day_09::part1::parse(&input)).is_ok());
+        assert!(day_09::part2::solve(&day_09::part2::parse(&input)).is_ok());
+    }
+}
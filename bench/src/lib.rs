@@ -0,0 +1,39 @@
+pub mod generate;
+
+use std::time::Duration;
+
+use criterion::{black_box, BenchmarkId, Criterion};
+
+// Benchmarks one part of a day, timing parse and solve separately so it's
+// clear which one a regression is in. Solve always gets its own parsed copy
+// of the input, made once up front.
+pub fn bench_part<'a, T, A>(
+    c: &mut Criterion,
+    name: &str,
+    inputs: &[(String, &'a str)],
+    parse: impl Fn(&'a str) -> T,
+    solve: impl Fn(&T) -> A,
+) {
+    let mut group = c.benchmark_group(name);
+
+    // Some of these take a good fraction of a second per run, so keep the
+    // whole suite down to a few minutes
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_secs(1));
+    group.measurement_time(Duration::from_secs(3));
+
+    for (input_name, input) in inputs {
+        group.bench_with_input(BenchmarkId::new("parse", input_name), input, |b, input| {
+            b.iter(|| parse(black_box(input)))
+        });
+
+        let parsed = parse(input);
+        group.bench_with_input(
+            BenchmarkId::new("solve", input_name),
+            &parsed,
+            |b, parsed| b.iter(|| solve(black_box(parsed))),
+        );
+    }
+
+    group.finish();
+}
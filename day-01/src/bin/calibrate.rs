@@ -0,0 +1,201 @@
+use day_01::calibration::{Calibration, Calibrator, Features, Mode};
+use day_01::vocabulary::Vocabulary;
+
+// Sums the calibration values of a document, reading numbers with a chosen
+// vocabulary. With no options this is part 2.
+//
+// Usage: cargo run --bin calibrate -- [--vocabulary english|english-numbers|german|french|none|FILE]
+//                                     [--words "uno 1, dos 2"] [--ignore-case]
+//                                     [--strict|--lenient] [--explain] [input file]
+//
+// A vocabulary file has a word and its value on each line, e.g. "twelve 12".
+// --words adds to the vocabulary, replacing any words it already has.
+//
+// Lines with no digits are an error unless --lenient is given, in which case
+// they're left out and listed. --explain shows the digits picked for every
+// line and where they were found.
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    vocabulary: Vocabulary,
+    ignore_case: bool,
+    mode: Mode,
+    explain: bool,
+    path: Option<String>,
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    let input = match args.path {
+        Some(path) => std::fs::read_to_string(path).expect("Should be able to read input file"),
+        None => include_str!("./input.txt").to_string(),
+    };
+
+    let features = Features::DigitsAndWords {
+        vocabulary: args.vocabulary,
+        ignore_case: args.ignore_case,
+    };
+
+    let calibration = match Calibrator::new(&features, args.mode).calibrate(&input) {
+        Ok(calibration) => calibration,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    if args.explain {
+        for line in explain(&input, &calibration) {
+            println!("{}", line);
+        }
+    } else {
+        for line in &calibration.skipped {
+            eprintln!("Skipped line {}: no digits", line);
+        }
+    }
+
+    println!("Result: {}", calibration.total());
+}
+
+fn explain(input: &str, calibration: &Calibration) -> Vec<String> {
+    let mut values = calibration.values.iter().peekable();
+
+    input
+        .lines()
+        .enumerate()
+        .map(
+            |(i, line)| match values.next_if(|value| value.line == i + 1) {
+                Some(value) => format!(
+                    "line {}: {} -> first {:?} at {}..{}, last {:?} at {}..{} -> {}",
+                    value.line,
+                    line,
+                    &line[value.first.start..value.first.end],
+                    value.first.start,
+                    value.first.end,
+                    &line[value.last.start..value.last.end],
+                    value.last.start,
+                    value.last.end,
+                    value.value()
+                ),
+                None => format!("line {}: {} -> no digits, skipped", i + 1, line),
+            },
+        )
+        .collect()
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut vocabulary = Vocabulary::english();
+    let mut extra = Vec::new();
+    let mut ignore_case = false;
+    let mut mode = Mode::Strict;
+    let mut explain = false;
+    let mut path = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+
+        match arg.as_str() {
+            "--vocabulary" => {
+                let name = value()?;
+                vocabulary = match Vocabulary::named(&name) {
+                    Some(vocabulary) => vocabulary,
+                    None => std::fs::read_to_string(&name)
+                        .map_err(|err| format!("Couldn't read vocabulary {}: {}", name, err))?
+                        .parse::<Vocabulary>()?,
+                };
+            }
+            "--words" => extra.extend(value()?.parse::<Vocabulary>()?.words),
+            "--ignore-case" => ignore_case = true,
+            "--strict" => mode = Mode::Strict,
+            "--lenient" => mode = Mode::Lenient,
+            "--explain" => explain = true,
+            _ => path = Some(arg),
+        }
+    }
+
+    for (word, value) in extra {
+        vocabulary.add(&word, value);
+    }
+
+    Ok(Args {
+        vocabulary,
+        ignore_case,
+        mode,
+        explain,
+        path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_args_works() {
+        assert_eq!(
+            parse_args(Vec::new()),
+            Ok(Args {
+                vocabulary: Vocabulary::english(),
+                ignore_case: false,
+                mode: Mode::Strict,
+                explain: false,
+                path: None,
+            })
+        );
+
+        assert_eq!(
+            parse_args(
+                [
+                    "--vocabulary",
+                    "none",
+                    "--words",
+                    "uno 1, doce 12",
+                    "--ignore-case",
+                    "--lenient",
+                    "--explain",
+                    "other.txt"
+                ]
+                .map(String::from)
+            ),
+            Ok(Args {
+                vocabulary: Vocabulary::new(&[("uno", 1), ("doce", 12)]),
+                ignore_case: true,
+                mode: Mode::Lenient,
+                explain: true,
+                path: Some("other.txt".to_string()),
+            })
+        );
+
+        assert!(parse_args(["--vocabulary", "no-such-file.txt"].map(String::from)).is_err());
+        assert!(parse_args(["--words", "uno"].map(String::from)).is_err());
+    }
+
+    #[test]
+    fn explain_works() {
+        let input = "xtwone3four\nabc";
+        let calibrator = Calibrator::new(
+            &Features::DigitsAndWords {
+                vocabulary: Vocabulary::english(),
+                ignore_case: false,
+            },
+            Mode::Lenient,
+        );
+        let calibration = calibrator.calibrate(input).unwrap();
+
+        assert_eq!(
+            explain(input, &calibration),
+            vec![
+                "line 1: xtwone3four -> first \"two\" at 1..4, last \"four\" at 7..11 -> 24",
+                "line 2: abc -> no digits, skipped",
+            ]
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+
+use crate::scanner::{Match, Scanner};
+use crate::vocabulary::Vocabulary;
+
+// What counts as a number
+#[derive(Debug, Clone, PartialEq)]
+pub enum Features {
+    // Part 1, just 0 to 9
+    Digits,
+    // Part 2, 0 to 9 and the words in the vocabulary
+    DigitsAndWords {
+        vocabulary: Vocabulary,
+        ignore_case: bool,
+    },
+}
+
+// What to do with a line that has no digits in it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    // Fail, saying which lines they were
+    Strict,
+    // Leave them out of the total, but keep track of them
+    Lenient,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalibrationError {
+    // Line numbers start at 1
+    NoDigits { lines: Vec<usize> },
+}
+
+impl fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalibrationError::NoDigits { lines } => {
+                let lines = lines
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "No digits on line(s) {}", lines)
+            }
+        }
+    }
+}
+
+// The digits picked for one line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineValue {
+    pub line: usize,
+    pub first: Match,
+    pub last: Match,
+}
+
+impl LineValue {
+    pub fn value(&self) -> u32 {
+        self.first.first_digit() * 10 + self.last.last_digit()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Calibration {
+    pub values: Vec<LineValue>,
+    // Lines with no digits, only ever filled in when lenient
+    pub skipped: Vec<usize>,
+}
+
+impl Calibration {
+    pub fn total(&self) -> u32 {
+        self.values.iter().map(|value| value.value()).sum()
+    }
+}
+
+// Both parts, and anything else that wants calibration values, go through
+// here so they all treat lines the same way
+#[derive(Debug, Clone)]
+pub struct Calibrator {
+    scanner: Scanner,
+    pub mode: Mode,
+}
+
+impl Calibrator {
+    pub fn new(features: &Features, mode: Mode) -> Calibrator {
+        let scanner = match features {
+            Features::Digits => Scanner::digits(),
+            Features::DigitsAndWords {
+                vocabulary,
+                ignore_case,
+            } => Scanner::new(vocabulary, *ignore_case),
+        };
+
+        Calibrator { scanner, mode }
+    }
+
+    // Lines without digits used to count as 0, so they're skipped
+    pub fn part1() -> Calibrator {
+        Calibrator::new(&Features::Digits, Mode::Lenient)
+    }
+
+    // Lines without digits used to panic, so they're an error
+    pub fn part2() -> Calibrator {
+        let features = Features::DigitsAndWords {
+            vocabulary: Vocabulary::english(),
+            ignore_case: false,
+        };
+        Calibrator::new(&features, Mode::Strict)
+    }
+
+    pub fn line_value(&self, line_number: usize, line: &str) -> Option<LineValue> {
+        Some(LineValue {
+            line: line_number,
+            first: self.scanner.first(line)?,
+            last: self.scanner.last(line)?,
+        })
+    }
+
+    pub fn calibrate(&self, input: &str) -> Result<Calibration, CalibrationError> {
+        self.calibrate_lines(input.lines())
+    }
+
+    pub fn calibrate_lines<'a>(
+        &self,
+        lines: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Calibration, CalibrationError> {
+        let mut calibration = Calibration::default();
+
+        for (i, line) in lines.into_iter().enumerate() {
+            match self.line_value(i + 1, line) {
+                Some(value) => calibration.values.push(value),
+                None => calibration.skipped.push(i + 1),
+            }
+        }
+
+        if self.mode == Mode::Strict && !calibration.skipped.is_empty() {
+            return Err(CalibrationError::NoDigits {
+                lines: calibration.skipped,
+            });
+        }
+
+        Ok(calibration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rng::Rng;
+
+    fn with_words(mode: Mode) -> Calibrator {
+        let features = Features::DigitsAndWords {
+            vocabulary: Vocabulary::english(),
+            ignore_case: false,
+        };
+        Calibrator::new(&features, mode)
+    }
+
+    #[test]
+    fn calibrate_works() {
+        let calibration = with_words(Mode::Lenient)
+            .calibrate("two1nine\nabc\nx7")
+            .unwrap();
+
+        assert_eq!(calibration.total(), 29 + 77);
+        assert_eq!(calibration.skipped, vec![2]);
+
+        let first = calibration.values[1];
+        assert_eq!(first.line, 3);
+        assert_eq!((first.first.start, first.last.end), (1, 2));
+    }
+
+    #[test]
+    fn strict_mode_reports_lines() {
+        let calibrator = Calibrator::new(&Features::Digits, Mode::Strict);
+        let result = calibrator.calibrate("one\n1\n\ntwo");
+
+        assert_eq!(
+            result,
+            Err(CalibrationError::NoDigits {
+                lines: vec![1, 3, 4]
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "No digits on line(s) 1, 3, 4"
+        );
+
+        assert_eq!(calibrator.calibrate("a1\n2b").unwrap().total(), 33);
+    }
+
+    // Part 1 as it was first written, scanning the characters by hand
+    fn hand_scan(input: &str) -> u32 {
+        input
+            .lines()
+            .map(|line| {
+                let digits = line
+                    .chars()
+                    .filter_map(|c| c.to_digit(10))
+                    .collect::<Vec<u32>>();
+
+                match (digits.first(), digits.last()) {
+                    (Some(first), Some(last)) => first * 10 + last,
+                    _ => 0,
+                }
+            })
+            .sum()
+    }
+
+    // Lines of letters, digits and bits of number words, some of them with no
+    // digits at all
+    fn random_input(seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+
+        let pieces = [
+            "one", "two", "eight", "nine", "oneight", "x", "q", "ten", "  ", "é",
+        ];
+        let mut input = String::new();
+
+        for _ in 0..50 {
+            for _ in 0..rng.next(8) {
+                match rng.next(3) {
+                    0 => input.push_str(&rng.next(10).to_string()),
+                    _ => input.push_str(pieces[rng.next(pieces.len() as u64) as usize]),
+                }
+            }
+            input.push('\n');
+        }
+
+        input
+    }
+
+    #[test]
+    fn part2_without_words_is_part1() {
+        let no_words = Features::DigitsAndWords {
+            vocabulary: Vocabulary::none(),
+            ignore_case: false,
+        };
+
+        for seed in 0..200 {
+            let input = random_input(seed);
+            let part1 = crate::part1::solve(&crate::part1::parse(&input));
+            assert_eq!(part1, hand_scan(&input), "{:?}", input);
+
+            let lenient = Calibrator::new(&no_words, Mode::Lenient)
+                .calibrate(&input)
+                .unwrap();
+            assert_eq!(lenient.total(), part1, "{:?}", input);
+
+            // Strict only differs in turning the skipped lines into an error
+            let strict = Calibrator::new(&no_words, Mode::Strict).calibrate(&input);
+            match strict {
+                Ok(strict) => {
+                    assert!(lenient.skipped.is_empty(), "{:?}", input);
+                    assert_eq!(strict.total(), part1, "{:?}", input);
+                }
+                Err(CalibrationError::NoDigits { lines }) => {
+                    assert_eq!(lines, lenient.skipped, "{:?}", input)
+                }
+            }
+        }
+    }
+}
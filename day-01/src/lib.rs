@@ -0,0 +1,5 @@
+pub mod calibration;
+pub mod part1;
+pub mod part2;
+pub mod scanner;
+pub mod vocabulary;
@@ -0,0 +1,57 @@
+/*
+ * This used to scan the characters of each line by hand. Part 2 needed a
+ * proper scanner, so both parts now go through the same calibration code
+ * and part 1 just leaves the words out.
+ *
+ * Lines without a digit are skipped, which is what returning 0 for them
+ * used to do.
+ */
+
+use crate::calibration::Calibrator;
+
+// All the work is in scanning the lines, so there's nothing to parse
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn solve(lines: &[&str]) -> u32 {
+    Calibrator::part1()
+        .calibrate_lines(lines.iter().copied())
+        .expect("Part 1 should skip lines without digits")
+        .total()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_line(line: &str) -> Option<u32> {
+        Calibrator::part1()
+            .line_value(1, line)
+            .map(|value| value.value())
+    }
+
+    #[test]
+    fn parse_line_works_for_two_numbers() {
+        let result = parse_line("ab1-2c");
+        assert_eq!(result, Some(12));
+    }
+
+    #[test]
+    fn parse_line_works_for_one_number() {
+        let result = parse_line("ab1c");
+        assert_eq!(result, Some(11));
+    }
+
+    #[test]
+    fn parse_line_works_for_no_numbers() {
+        let result = parse_line("abc");
+        assert_eq!(result, None)
+    }
+
+    #[test]
+    fn parse_line_works_for_three_numbers() {
+        let result = parse_line("ab1-2-3c");
+        assert_eq!(result, Some(13))
+    }
+}
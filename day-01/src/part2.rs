@@ -0,0 +1,85 @@
+use crate::calibration::{CalibrationError, Calibrator};
+
+/*
+ * This started out as a regex run against every substring of the line,
+ * because match iter with a regex doesnt return overlapping matches.
+ *
+ * The scanner finds overlapping words like "oneight" without that. It
+ * reads forwards for the first digit and backwards for the last one.
+ */
+
+// All the work is in scanning the lines, so there's nothing to parse
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn solve(lines: &[&str]) -> Result<u32, CalibrationError> {
+    Ok(Calibrator::part2()
+        .calibrate_lines(lines.iter().copied())?
+        .total())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_line(calibrator: &Calibrator, line: &str) -> u32 {
+        calibrator
+            .line_value(1, line)
+            .expect("Line should have a digit")
+            .value()
+    }
+
+    #[test]
+    fn line_example_works() {
+        let calibrator = Calibrator::part2();
+
+        let input: &str = "two1nine";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 29);
+
+        let input: &str = "eightwothree";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 83);
+
+        let input: &str = "abcone2threexyz";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 13);
+
+        let input: &str = "xtwone3four";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 24);
+
+        let input: &str = "4nineeightseven2";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 42);
+
+        let input: &str = "zoneight234";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 14);
+
+        let input: &str = "7pqrstsixteen";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 76);
+
+        let input: &str = "28gtbkszmrtmnineoneightmx";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 28);
+
+        let input: &str = "eighthreeoneight";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 88);
+
+        let input: &str = "twone";
+        let result: u32 = parse_line(&calibrator, input);
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn part_2_fails_without_digits() {
+        assert_eq!(
+            solve(&parse("one\nabc")),
+            Err(CalibrationError::NoDigits { lines: vec![2] })
+        );
+    }
+}
@@ -0,0 +1,399 @@
+use std::collections::BTreeMap;
+
+use crate::vocabulary::Vocabulary;
+
+// An Aho-Corasick automaton: a trie of every word, where each state also has
+// a fail link to the longest suffix of it that is a prefix of some word. When
+// the next byte doesn't carry on from a state we follow fail links instead of
+// going back to the start of the line, so every match is found, overlapping
+// ones like "oneight" included, in a single pass.
+#[derive(Debug, Clone)]
+struct Automaton {
+    states: Vec<State>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    next: BTreeMap<u8, usize>,
+    fail: usize,
+    // (length, value) of every word ending here, including the ones we'd
+    // reach by following fail links
+    outputs: Vec<(usize, u32)>,
+}
+
+impl Automaton {
+    fn new<'a>(words: impl Iterator<Item = (&'a [u8], u32)>) -> Automaton {
+        let mut states = vec![State::default()];
+
+        for (word, value) in words {
+            let mut state = 0;
+
+            for byte in word {
+                state = match states[state].next.get(byte) {
+                    Some(next) => *next,
+                    None => {
+                        states.push(State::default());
+                        let next = states.len() - 1;
+                        states[state].next.insert(*byte, next);
+                        next
+                    }
+                };
+            }
+
+            states[state].outputs.push((word.len(), value));
+        }
+
+        // Fill in fail links breadth first, so a state's fail link is always
+        // shorter than it and already done
+        let mut queue = states[0]
+            .next
+            .values()
+            .copied()
+            .collect::<std::collections::VecDeque<usize>>();
+
+        while let Some(state) = queue.pop_front() {
+            let next = states[state]
+                .next
+                .iter()
+                .map(|(byte, next)| (*byte, *next))
+                .collect::<Vec<(u8, usize)>>();
+
+            for (byte, child) in next {
+                let mut fail = states[state].fail;
+                while fail != 0 && !states[fail].next.contains_key(&byte) {
+                    fail = states[fail].fail;
+                }
+
+                let fail = states[fail].next.get(&byte).copied().unwrap_or(0);
+                states[child].fail = fail;
+
+                let inherited = states[fail].outputs.clone();
+                states[child].outputs.extend(inherited);
+
+                queue.push_back(child);
+            }
+        }
+
+        Automaton { states }
+    }
+
+    fn step(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            if let Some(next) = self.states[state].next.get(&byte) {
+                return *next;
+            }
+
+            if state == 0 {
+                return 0;
+            }
+
+            state = self.states[state].fail;
+        }
+    }
+}
+
+// A number found in a line. `start..end` are byte offsets into the line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+impl Match {
+    // The digit to use when this is the first match in a line, so "twelve"
+    // counts as a 1
+    pub fn first_digit(&self) -> u32 {
+        let mut value = self.value;
+        while value >= 10 {
+            value /= 10;
+        }
+        value
+    }
+
+    // The digit to use when this is the last match in a line, so "twelve"
+    // counts as a 2
+    pub fn last_digit(&self) -> u32 {
+        self.value % 10
+    }
+}
+
+// Finds digits, and the words of a vocabulary, in a line. The first one
+// comes from a left to right pass and the last one from a right to left pass
+// over the reversed words, so neither has to look at the whole line unless
+// the digits are all in the middle.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    forward: Automaton,
+    backward: Automaton,
+    longest: usize,
+    // Only ASCII letters are folded, so "FÜNF" won't match "fünf"
+    ignore_case: bool,
+}
+
+const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+impl Scanner {
+    pub fn new(vocabulary: &Vocabulary, ignore_case: bool) -> Scanner {
+        let words = DIGITS
+            .iter()
+            .map(|(digit, value)| (digit.as_bytes().to_vec(), *value))
+            .chain(vocabulary.words.iter().map(|(word, value)| {
+                let word = if ignore_case {
+                    word.to_ascii_lowercase()
+                } else {
+                    word.clone()
+                };
+                (word.into_bytes(), *value)
+            }))
+            .collect::<Vec<(Vec<u8>, u32)>>();
+
+        let reversed = words
+            .iter()
+            .map(|(word, value)| (word.iter().rev().copied().collect::<Vec<u8>>(), *value))
+            .collect::<Vec<(Vec<u8>, u32)>>();
+
+        Scanner {
+            forward: Automaton::new(words.iter().map(|(word, value)| (&word[..], *value))),
+            backward: Automaton::new(reversed.iter().map(|(word, value)| (&word[..], *value))),
+            longest: words.iter().map(|(word, _)| word.len()).max().unwrap_or(0),
+            ignore_case,
+        }
+    }
+
+    // Part 1, just 0 to 9
+    pub fn digits() -> Scanner {
+        Scanner::new(&Vocabulary::none(), false)
+    }
+
+    // Part 2, 0 to 9 and "one" to "nine"
+    pub fn digits_and_words() -> Scanner {
+        Scanner::new(&Vocabulary::english(), false)
+    }
+
+    fn fold(&self, byte: u8) -> u8 {
+        if self.ignore_case {
+            byte.to_ascii_lowercase()
+        } else {
+            byte
+        }
+    }
+
+    // Every match in the line, overlapping ones included, in the order they
+    // end. Words ending at the same place come longest first.
+    pub fn matches(&self, line: &str) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut state = 0;
+
+        for (i, byte) in line.bytes().enumerate() {
+            state = self.forward.step(state, self.fold(byte));
+
+            for (length, value) in &self.forward.states[state].outputs {
+                matches.push(Match {
+                    start: i + 1 - length,
+                    end: i + 1,
+                    value: *value,
+                });
+            }
+        }
+
+        matches
+    }
+
+    // The match starting furthest left, the longest one if there's a tie
+    pub fn first(&self, line: &str) -> Option<Match> {
+        let mut best: Option<Match> = None;
+        let mut state = 0;
+
+        for (i, byte) in line.bytes().enumerate() {
+            // Anything that ends from here on starts after the best one
+            if let Some(best) = best {
+                if i >= best.start + self.longest {
+                    break;
+                }
+            }
+
+            state = self.forward.step(state, self.fold(byte));
+
+            for (length, value) in &self.forward.states[state].outputs {
+                let found = Match {
+                    start: i + 1 - length,
+                    end: i + 1,
+                    value: *value,
+                };
+
+                best = match best {
+                    Some(best)
+                        if best.start < found.start
+                            || (best.start == found.start && best.end >= found.end) =>
+                    {
+                        Some(best)
+                    }
+                    _ => Some(found),
+                };
+            }
+        }
+
+        best
+    }
+
+    // The match starting furthest right, the longest one if there's a tie.
+    // Going backwards, a match is found as soon as we reach its start, so the
+    // first state with any outputs has the answer.
+    pub fn last(&self, line: &str) -> Option<Match> {
+        let bytes = line.as_bytes();
+        let mut state = 0;
+
+        for start in (0..bytes.len()).rev() {
+            state = self.backward.step(state, self.fold(bytes[start]));
+
+            let longest = self.backward.states[state].outputs.iter().max();
+
+            if let Some((length, value)) = longest {
+                return Some(Match {
+                    start,
+                    end: start + length,
+                    value: *value,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(matches: &[Match]) -> Vec<u32> {
+        matches.iter().map(|m| m.value).collect()
+    }
+
+    #[test]
+    fn matches_finds_overlapping_words() {
+        let scanner = Scanner::digits_and_words();
+
+        assert_eq!(values(&scanner.matches("oneight")), vec![1, 8]);
+        assert_eq!(values(&scanner.matches("twone3")), vec![2, 1, 3]);
+        assert_eq!(values(&scanner.matches("sevenine")), vec![7, 9]);
+        assert_eq!(values(&scanner.matches("eighthree")), vec![8, 3]);
+        assert_eq!(values(&scanner.matches("xyz")), vec![]);
+
+        assert_eq!(
+            scanner.matches("a5nine"),
+            vec![
+                Match {
+                    start: 1,
+                    end: 2,
+                    value: 5
+                },
+                Match {
+                    start: 2,
+                    end: 6,
+                    value: 9
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_follows_fail_links() {
+        // "sevenin" has to fall back to "nin" to find "nine"
+        let scanner = Scanner::digits_and_words();
+        assert_eq!(values(&scanner.matches("sevseveninine")), vec![7, 9]);
+        assert_eq!(values(&scanner.matches("ninine")), vec![9]);
+    }
+
+    #[test]
+    fn first_and_last_work() {
+        let scanner = Scanner::digits_and_words();
+
+        let first = scanner.first("zoneight234").unwrap();
+        assert_eq!((first.start, first.value), (1, 1));
+
+        let last = scanner.last("zoneight").unwrap();
+        assert_eq!((last.start, last.end, last.value), (3, 8, 8));
+
+        assert_eq!(scanner.first("abc"), None);
+        assert_eq!(scanner.last(""), None);
+    }
+
+    #[test]
+    fn first_and_last_agree_with_matches() {
+        let scanner = Scanner::digits_and_words();
+        let lines = [
+            "two1nine",
+            "eightwothree",
+            "xtwone3four",
+            "4nineeightseven2",
+            "7pqrstsixteen",
+            "28gtbkszmrtmnineoneightmx",
+            "threeeighthreeightwo",
+        ];
+
+        for line in lines {
+            let matches = scanner.matches(line);
+            let first = matches.iter().min_by_key(|m| m.start).copied();
+            let last = matches.iter().max_by_key(|m| m.start).copied();
+
+            assert_eq!(scanner.first(line), first, "{}", line);
+            assert_eq!(scanner.last(line), last, "{}", line);
+        }
+    }
+
+    #[test]
+    fn longest_wins_a_tie() {
+        let vocabulary = Vocabulary::new(&[("eight", 8), ("eighteen", 18), ("teen", 10)]);
+        let scanner = Scanner::new(&vocabulary, false);
+
+        assert_eq!(scanner.first("eighteen").map(|m| m.value), Some(18));
+        assert_eq!(scanner.last("eighteenx").map(|m| m.value), Some(10));
+
+        let vocabulary = Vocabulary::new(&[("eight", 8), ("eighteen", 18)]);
+        let scanner = Scanner::new(&vocabulary, false);
+        assert_eq!(scanner.last("xeighteen").map(|m| m.value), Some(18));
+        assert_eq!(scanner.last("eightx").map(|m| m.value), Some(8));
+    }
+
+    #[test]
+    fn scanner_uses_vocabulary() {
+        let scanner = Scanner::new(&Vocabulary::named("german").unwrap(), false);
+        assert_eq!(values(&scanner.matches("fünfzweiacht")), vec![5, 2, 8]);
+        assert_eq!(values(&scanner.matches("one")), vec![]);
+
+        let scanner = Scanner::new(&Vocabulary::named("english-numbers").unwrap(), false);
+        let first = scanner.first("twelvex3").unwrap();
+        assert_eq!((first.value, first.first_digit()), (12, 1));
+        let last = scanner.last("5xxtwentyx").unwrap();
+        assert_eq!((last.value, last.last_digit()), (20, 0));
+
+        // Digits are always there, even with no words
+        assert_eq!(values(&Scanner::digits().matches("one2")), vec![2]);
+    }
+
+    #[test]
+    fn scanner_can_ignore_case() {
+        let scanner = Scanner::new(&Vocabulary::english(), true);
+        assert_eq!(values(&scanner.matches("OneIGHT")), vec![1, 8]);
+        assert_eq!(scanner.last("xNINEx").map(|m| m.value), Some(9));
+
+        let vocabulary = Vocabulary::new(&[("Zwölf", 12)]);
+        let scanner = Scanner::new(&vocabulary, true);
+        assert_eq!(values(&scanner.matches("zwölf")), vec![12]);
+
+        let scanner = Scanner::new(&Vocabulary::english(), false);
+        assert_eq!(values(&scanner.matches("One")), vec![]);
+    }
+}
@@ -0,0 +1,197 @@
+use std::str::FromStr;
+
+// The words the scanner should read as numbers, on top of the digits
+// themselves. A word can stand for more than one digit, e.g. "twelve" is a 1
+// followed by a 2.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vocabulary {
+    pub words: Vec<(String, u32)>,
+}
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const ENGLISH_NUMBERS: [(&str, u32); 19] = [
+    ("zero", 0),
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+    ("twenty", 20),
+    ("thirty", 30),
+    ("forty", 40),
+    ("fifty", 50),
+    ("sixty", 60),
+    ("seventy", 70),
+    ("eighty", 80),
+    ("ninety", 90),
+];
+
+const GERMAN: [(&str, u32); 9] = [
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+const FRENCH: [(&str, u32); 9] = [
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+impl Vocabulary {
+    pub fn new(words: &[(&str, u32)]) -> Vocabulary {
+        Vocabulary {
+            words: words
+                .iter()
+                .map(|(word, value)| (word.to_string(), *value))
+                .collect(),
+        }
+    }
+
+    // No words at all, just digits
+    pub fn none() -> Vocabulary {
+        Vocabulary { words: Vec::new() }
+    }
+
+    // Part 2, "one" to "nine"
+    pub fn english() -> Vocabulary {
+        Vocabulary::new(&ENGLISH)
+    }
+
+    // Replaces the value of a word we already have
+    pub fn add(&mut self, word: &str, value: u32) {
+        match self.words.iter_mut().find(|(existing, _)| existing == word) {
+            Some(entry) => entry.1 = value,
+            None => self.words.push((word.to_string(), value)),
+        }
+    }
+
+    pub fn named(name: &str) -> Option<Vocabulary> {
+        match name {
+            "none" => Some(Vocabulary::none()),
+            "english" => Some(Vocabulary::english()),
+            // "one" to "nine" plus zero, the teens and the tens
+            "english-numbers" => Some(Vocabulary::new(
+                &[&ENGLISH[..], &ENGLISH_NUMBERS[..]].concat(),
+            )),
+            "german" => Some(Vocabulary::new(&GERMAN)),
+            "french" => Some(Vocabulary::new(&FRENCH)),
+            _ => None,
+        }
+    }
+}
+
+// One word and its value per line, e.g. "twelve 12", or the same separated by
+// commas on one line. Blank lines and lines starting with # are skipped.
+impl FromStr for Vocabulary {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words: Vec<(String, u32)> = Vec::new();
+
+        let entries = s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.starts_with('#'))
+            .flat_map(|line| line.split(','))
+            .map(|entry| entry.trim())
+            .filter(|entry| !entry.is_empty());
+
+        for entry in entries {
+            let (word, value) = entry.split_once(char::is_whitespace).ok_or(format!(
+                "Invalid word: {:?}, expected a word and a value",
+                entry
+            ))?;
+
+            let value = value
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid value for {:?}: {:?}", word, value.trim()))?;
+
+            match words.iter().find(|(existing, _)| existing == word) {
+                Some((_, existing)) if *existing != value => {
+                    return Err(format!("{:?} is both {} and {}", word, existing, value))
+                }
+                Some(_) => {}
+                None => words.push((word.to_string(), value)),
+            }
+        }
+
+        Ok(Vocabulary { words })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_works() {
+        assert_eq!(Vocabulary::named("english"), Some(Vocabulary::english()));
+        assert_eq!(Vocabulary::named("german").unwrap().words[4].0, "fünf");
+        assert_eq!(
+            Vocabulary::named("english-numbers").unwrap().words.len(),
+            28
+        );
+        assert_eq!(Vocabulary::named("klingon"), None);
+    }
+
+    #[test]
+    fn parse_works() {
+        let vocabulary = "# Spanish
+uno 1
+dos 2
+
+doce 12, veinte 20"
+            .parse::<Vocabulary>()
+            .unwrap();
+
+        assert_eq!(
+            vocabulary,
+            Vocabulary::new(&[("uno", 1), ("dos", 2), ("doce", 12), ("veinte", 20)])
+        );
+
+        assert!("uno".parse::<Vocabulary>().is_err());
+        assert!("uno one".parse::<Vocabulary>().is_err());
+        assert!("uno 1, uno 2".parse::<Vocabulary>().is_err());
+        assert_eq!("uno 1, uno 1".parse::<Vocabulary>().unwrap().words.len(), 1);
+    }
+
+    #[test]
+    fn add_replaces_words() {
+        let mut vocabulary = Vocabulary::english();
+        vocabulary.add("one", 7);
+        vocabulary.add("ten", 10);
+
+        assert_eq!(vocabulary.words[0], ("one".to_string(), 7));
+        assert_eq!(vocabulary.words.len(), 10);
+    }
+}
@@ -0,0 +1,217 @@
+use day_02::inference::{sample_game, Estimator};
+use day_02::{parse_hand, parse_input_line, Cubes, Game};
+
+// Prints the most likely bag for every game, with a 95% interval for each
+// colour. With --simulate it instead plays games from a known bag and checks
+// how often the intervals cover the real counts.
+//
+// Draws that look like they were taken with replacement get more likely the
+// bigger the bag is, so those games end up near --limit with open intervals.
+//
+// Usage: cargo run --release --bin infer -- [--limit N] [input file]
+//        cargo run --release --bin infer -- --simulate "12 red, 13 green, 14 blue"
+//                   [--games N] [--draws N] [--size N] [--seed N] [--limit N]
+
+#[derive(Debug, PartialEq)]
+struct Simulation {
+    bag: Cubes,
+    games: u32,
+    draws: usize,
+    size: u32,
+    seed: u64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    estimator: Estimator,
+    simulation: Option<Simulation>,
+    path: Option<String>,
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    match args.simulation {
+        Some(simulation) => simulate(&args.estimator, &simulation),
+        None => {
+            let input = match args.path {
+                Some(path) => {
+                    std::fs::read_to_string(path).expect("Should be able to read input file")
+                }
+                None => include_str!("./input.txt").to_string(),
+            };
+
+            match input
+                .lines()
+                .map(parse_input_line)
+                .collect::<Result<Vec<Game>, _>>()
+            {
+                Ok(games) => infer(&args.estimator, &games),
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+fn infer(estimator: &Estimator, games: &[Game]) {
+    for game in games {
+        let estimate = estimator.estimate(game);
+        let intervals = estimate
+            .bag
+            .iter()
+            .map(|(colour, count)| format!("{} {} [{}]", count, colour, estimate.intervals[colour]))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        println!(
+            "Game {}: minimum {} estimate {}",
+            game.id,
+            game.minimum_bag(),
+            intervals
+        );
+    }
+}
+
+fn simulate(estimator: &Estimator, simulation: &Simulation) {
+    let estimates = (0..simulation.games)
+        .map(|id| {
+            let game = sample_game(
+                id + 1,
+                &simulation.bag,
+                simulation.draws,
+                simulation.size,
+                simulation.seed.wrapping_add(id as u64),
+            );
+            estimator.estimate(&game)
+        })
+        .collect::<Vec<_>>();
+
+    println!("colour  actual  mean estimate  coverage");
+
+    for (colour, actual) in simulation.bag.iter() {
+        let mean = estimates
+            .iter()
+            .map(|estimate| estimate.bag.get(colour) as f64)
+            .sum::<f64>()
+            / estimates.len() as f64;
+
+        // A colour that never came out of the bag has no interval, which
+        // counts as missing the real count
+        let covered = estimates
+            .iter()
+            .filter(|estimate| {
+                estimate
+                    .intervals
+                    .get(colour)
+                    .is_some_and(|interval| interval.contains(actual))
+            })
+            .count();
+
+        println!(
+            "{:<6}  {:>6}  {:>13.2}  {:>7.1}%",
+            colour,
+            actual,
+            mean,
+            100.0 * covered as f64 / estimates.len() as f64
+        );
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut estimator = Estimator::default();
+    let mut bag = None;
+    let mut games = 100;
+    let mut draws = 5;
+    let mut size = 10;
+    let mut seed = 1;
+    let mut path = None;
+    let mut args = args.into_iter();
+
+    fn number<T: std::str::FromStr>(arg: &str, value: String) -> Result<T, String> {
+        value
+            .parse()
+            .map_err(|_| format!("Invalid value for {}: {:?}", arg, value))
+    }
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+
+        match arg.as_str() {
+            "--limit" => estimator.limit = number(&arg, value()?)?,
+            "--simulate" => {
+                let value = value()?;
+                let cubes = parse_hand(&value).map_err(|err| err.to_string())?;
+                if cubes.total() == 0 {
+                    return Err(format!("Invalid bag: {:?}", value));
+                }
+                bag = Some(cubes);
+            }
+            "--games" => games = number(&arg, value()?)?,
+            "--draws" => draws = number(&arg, value()?)?,
+            "--size" => size = number(&arg, value()?)?,
+            "--seed" => seed = number(&arg, value()?)?,
+            _ => path = Some(arg),
+        }
+    }
+
+    if games == 0 {
+        return Err("Need at least one game to simulate".to_string());
+    }
+
+    Ok(Args {
+        estimator,
+        simulation: bag.map(|bag| Simulation {
+            bag,
+            games,
+            draws,
+            size,
+            seed,
+        }),
+        path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_args_works() {
+        assert_eq!(
+            parse_args(["--limit", "40", "other.txt"].map(String::from)),
+            Ok(Args {
+                estimator: Estimator { limit: 40 },
+                simulation: None,
+                path: Some("other.txt".to_string()),
+            })
+        );
+
+        assert_eq!(
+            parse_args(["--simulate", "3 red, 2 blue", "--draws", "8"].map(String::from)),
+            Ok(Args {
+                estimator: Estimator::default(),
+                simulation: Some(Simulation {
+                    bag: Cubes::from_iter([("red", 3), ("blue", 2)]),
+                    games: 100,
+                    draws: 8,
+                    size: 10,
+                    seed: 1,
+                }),
+                path: None,
+            })
+        );
+
+        assert!(parse_args(["--simulate", "lots"].map(String::from)).is_err());
+        assert!(parse_args(["--size", "-1"].map(String::from)).is_err());
+        assert!(parse_args(["--limit"].map(String::from)).is_err());
+    }
+}
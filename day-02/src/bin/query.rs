@@ -0,0 +1,120 @@
+use day_02::parse_input_line;
+use day_02::rules::{Rule, Rules};
+
+// Checks every game against a set of rules, printing each rule a game breaks
+// and the draw it broke it on. With no rules this is the part 1 bag.
+//
+// Usage: cargo run --bin query -- [--bag "12 red, 13 green, 14 blue"]
+//                                 [--max-total N] [--contains COLOUR]
+//                                 [--config rules.txt] [input file]
+//
+// The config file has one rule per line in the same form, e.g. "max-total 20".
+
+fn main() {
+    let (rules, path) = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    let input = match path {
+        Some(path) => std::fs::read_to_string(path).expect("Should be able to read input file"),
+        None => include_str!("./input.txt").to_string(),
+    };
+
+    let mut total = 0;
+
+    for game in input.lines().map(parse_input_line) {
+        let game = match game {
+            Ok(game) => game,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1);
+            }
+        };
+        let failures = rules.failures(&game);
+
+        for failure in &failures {
+            println!("{}", failure);
+        }
+
+        if failures.is_empty() {
+            total += game.id;
+        }
+    }
+
+    println!("Result: {}", total);
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<(Rules, Option<String>), String> {
+    let mut rules = Vec::new();
+    let mut path = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+
+        match arg.as_str() {
+            "--bag" => rules.push(format!("bag {}", value()?).parse::<Rule>()?),
+            "--max-total" => rules.push(format!("max-total {}", value()?).parse::<Rule>()?),
+            "--contains" => rules.push(format!("contains {}", value()?).parse::<Rule>()?),
+            "--config" => {
+                let config = value()?;
+                let text = std::fs::read_to_string(&config)
+                    .map_err(|err| format!("Couldn't read {}: {}", config, err))?;
+                rules.extend(text.parse::<Rules>()?.rules);
+            }
+            _ => path = Some(arg),
+        }
+    }
+
+    let rules = if rules.is_empty() {
+        Rules::standard()
+    } else {
+        Rules { rules }
+    };
+
+    Ok((rules, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day_02::Cubes;
+
+    #[test]
+    fn parse_args_works() {
+        let result = parse_args(
+            [
+                "--bag",
+                "2 red, 1 blue",
+                "--max-total",
+                "3",
+                "--contains",
+                "blue",
+                "other.txt",
+            ]
+            .map(String::from),
+        );
+
+        assert_eq!(
+            result,
+            Ok((
+                Rules {
+                    rules: vec![
+                        Rule::Bag(Cubes::from_iter([("red", 2), ("blue", 1)])),
+                        Rule::MaxTotal(3),
+                        Rule::Contains("blue".to_string()),
+                    ]
+                },
+                Some("other.txt".to_string())
+            ))
+        );
+
+        assert_eq!(parse_args(Vec::new()), Ok((Rules::standard(), None)));
+        assert!(parse_args(["--max-total", "many"].map(String::from)).is_err());
+        assert!(parse_args(["--config"].map(String::from)).is_err());
+    }
+}
@@ -0,0 +1,457 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use rng::Rng;
+
+use crate::{Cubes, Game};
+
+// Estimates what was in the bag from the draws in a game, assuming every draw
+// takes some cubes out without replacement and puts them back afterwards.
+// Then a draw of k_c cubes of each colour from a bag of n_c has probability
+//
+//   C(n_red, k_red) * C(n_green, k_green) * ... / C(n, k)
+//
+// and the likelihood of a bag is the product over the draws. The minimum bag
+// is only the smallest bag that could have worked; this finds the one that
+// makes the draws most likely.
+
+// Half the 95% point of chi-squared with one degree of freedom. Every count
+// whose profile log likelihood is within this of the best is in the interval.
+const CUTOFF: f64 = 1.920_729_4;
+
+// Stops float noise from counting as a better bag
+const EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimator {
+    // The most cubes of any one colour to try
+    pub limit: u32,
+}
+
+impl Default for Estimator {
+    fn default() -> Estimator {
+        Estimator { limit: 100 }
+    }
+}
+
+// `high` is None if the interval was still going at the search limit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub low: u32,
+    pub high: Option<u32>,
+}
+
+impl Interval {
+    pub fn contains(&self, count: u32) -> bool {
+        count >= self.low && self.high.is_none_or(|high| count <= high)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.high {
+            Some(high) => write!(f, "{}..={}", self.low, high),
+            None => write!(f, "{}..", self.low),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub bag: Cubes,
+    pub log_likelihood: f64,
+    // 95% profile likelihood interval for each colour
+    pub intervals: BTreeMap<String, Interval>,
+}
+
+impl Estimator {
+    pub fn estimate(&self, game: &Game) -> Estimate {
+        let draws = Draws::new(game, self.limit);
+        let search = draws.search();
+
+        let intervals = draws
+            .colours
+            .iter()
+            .zip(&search.profiles)
+            .map(|(colour, profile)| {
+                let inside = (0..profile.len())
+                    .filter(|count| profile[*count] >= search.log_likelihood - CUTOFF)
+                    .collect::<Vec<usize>>();
+
+                let low = *inside.first().expect("Best count should be inside") as u32;
+                let high = *inside.last().expect("Best count should be inside") as u32;
+
+                let interval = Interval {
+                    low,
+                    high: if high >= draws.limit {
+                        None
+                    } else {
+                        Some(high)
+                    },
+                };
+                (colour.clone(), interval)
+            })
+            .collect();
+
+        let bag = draws
+            .colours
+            .iter()
+            .zip(&search.bag)
+            .map(|(colour, count)| (colour.as_str(), *count))
+            .collect();
+
+        Estimate {
+            bag,
+            log_likelihood: search.log_likelihood,
+            intervals,
+        }
+    }
+}
+
+// The log likelihood splits into a part for each colour and a part for the
+// total, so both are worked out up front for every count:
+//
+//   by_colour[c][n] = sum over draws of ln C(n, k_c)
+//   by_total[n]     = sum over draws of ln C(n, k)
+//
+// The colours only depend on each other through the total, so rather than
+// trying all limit ^ colours bags, the search finds the best way to make up
+// each total from the colours one at a time.
+struct Draws {
+    colours: Vec<String>,
+    minimum: Vec<u32>,
+    // The most of any one colour, which is never below the minimum bag
+    limit: u32,
+    by_colour: Vec<Vec<f64>>,
+    by_total: Vec<f64>,
+}
+
+struct Search {
+    bag: Vec<u32>,
+    log_likelihood: f64,
+    // For each colour and count, the best log likelihood of any bag with
+    // that many of that colour
+    profiles: Vec<Vec<f64>>,
+}
+
+impl Draws {
+    fn new(game: &Game, limit: u32) -> Draws {
+        let minimum_bag = game.minimum_bag();
+        let colours = minimum_bag
+            .colours()
+            .map(|colour| colour.to_string())
+            .collect::<Vec<String>>();
+        let minimum = colours
+            .iter()
+            .map(|colour| minimum_bag.get(colour))
+            .collect::<Vec<u32>>();
+        let limit = minimum.iter().fold(limit, |a, b| a.max(*b));
+
+        let mut ln_factorials = vec![0.0];
+        for n in 1..=(limit as usize * colours.len()) {
+            ln_factorials.push(ln_factorials[n - 1] + (n as f64).ln());
+        }
+
+        let ln_choose = |n: usize, k: usize| {
+            if k > n {
+                f64::NEG_INFINITY
+            } else {
+                ln_factorials[n] - ln_factorials[k] - ln_factorials[n - k]
+            }
+        };
+
+        let by_colour = colours
+            .iter()
+            .map(|colour| {
+                (0..=limit as usize)
+                    .map(|n| {
+                        game.hands
+                            .iter()
+                            .map(|hand| ln_choose(n, hand.get(colour) as usize))
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Only counting the colours we're estimating, in case a hand has
+        // something like "0 red" in it
+        let sizes = game
+            .hands
+            .iter()
+            .map(|hand| colours.iter().map(|colour| hand.get(colour)).sum::<u32>() as usize)
+            .collect::<Vec<usize>>();
+
+        let by_total = (0..ln_factorials.len())
+            .map(|n| sizes.iter().map(|k| ln_choose(n, *k)).sum())
+            .collect();
+
+        Draws {
+            colours,
+            minimum,
+            limit,
+            by_colour,
+            by_total,
+        }
+    }
+
+    fn log_likelihood(&self, bag: &[u32]) -> f64 {
+        let by_colour = bag
+            .iter()
+            .zip(&self.by_colour)
+            .map(|(count, by_colour)| by_colour[*count as usize])
+            .sum::<f64>();
+
+        // Otherwise a bag too small for a draw would be -inf - -inf
+        if by_colour == f64::NEG_INFINITY {
+            return by_colour;
+        }
+
+        by_colour - self.by_total[bag.iter().sum::<u32>() as usize]
+    }
+
+    // The best bag from the minimum up to the limit. Ties go to the smallest
+    // bag, which keeps a flat likelihood at the minimum bag.
+    fn search(&self) -> Search {
+        let totals = self.by_total.len();
+        let counts = |colour: usize| self.minimum[colour] as usize..=self.limit as usize;
+
+        // The best sum of by_colour for each total, using the colours before
+        // (forward) or from (backward) each one
+        let add = |best: &[f64], colour: usize| {
+            let mut next = vec![f64::NEG_INFINITY; totals];
+            for (total, value) in best.iter().enumerate() {
+                if *value == f64::NEG_INFINITY {
+                    continue;
+                }
+                for count in counts(colour).filter(|count| total + count < totals) {
+                    let entry = &mut next[total + count];
+                    *entry = entry.max(value + self.by_colour[colour][count]);
+                }
+            }
+            next
+        };
+
+        let mut empty = vec![f64::NEG_INFINITY; totals];
+        empty[0] = 0.0;
+
+        let mut forward = vec![empty.clone()];
+        for colour in 0..self.colours.len() {
+            forward.push(add(&forward[colour], colour));
+        }
+
+        let mut backward = vec![empty];
+        for colour in (0..self.colours.len()).rev() {
+            backward.push(add(&backward[backward.len() - 1], colour));
+        }
+        backward.reverse();
+
+        // Totals below the minimum bag can't be made at all
+        let all = &forward[self.colours.len()];
+        let smallest = self.minimum.iter().sum::<u32>() as usize;
+        let mut total = smallest;
+        for t in smallest..totals {
+            if all[t] - self.by_total[t] > all[total] - self.by_total[total] + EPSILON {
+                total = t;
+            }
+        }
+
+        // Walk back from the last colour, taking the fewest cubes that still
+        // make the best sum
+        let mut bag = self.minimum.clone();
+        for colour in (0..self.colours.len()).rev() {
+            let count = counts(colour)
+                .filter(|count| *count <= total)
+                .find(|count| {
+                    forward[colour][total - count] + self.by_colour[colour][*count]
+                        >= forward[colour + 1][total] - EPSILON
+                })
+                .expect("Best sum should come from some count");
+            bag[colour] = count as u32;
+            total -= count;
+        }
+
+        // For each colour, the best of the other colours for each total, then
+        // the best bag for each count of this one
+        let profiles = (0..self.colours.len())
+            .map(|colour| {
+                let mut others = vec![f64::NEG_INFINITY; totals];
+                for (a, before) in forward[colour].iter().enumerate() {
+                    for (b, after) in backward[colour + 1].iter().enumerate() {
+                        if a + b < totals {
+                            others[a + b] = others[a + b].max(before + after);
+                        }
+                    }
+                }
+
+                let mut profile = vec![f64::NEG_INFINITY; self.limit as usize + 1];
+                for count in counts(colour) {
+                    profile[count] = (0..totals - count)
+                        .filter(|t| others[*t] != f64::NEG_INFINITY)
+                        .map(|t| {
+                            others[t] + self.by_colour[colour][count] - self.by_total[t + count]
+                        })
+                        .fold(f64::NEG_INFINITY, f64::max);
+                }
+                profile
+            })
+            .collect();
+
+        Search {
+            log_likelihood: self.log_likelihood(&bag),
+            bag,
+            profiles,
+        }
+    }
+}
+
+// -------- SAMPLING ---------
+
+// A game of `draws` draws of `size` cubes each from a known bag, for checking
+// the estimator. The same seed always gives the same game.
+pub fn sample_game(id: u32, bag: &Cubes, draws: usize, size: u32, seed: u64) -> Game {
+    let mut rng = Rng::new(seed);
+
+    let mut cubes = bag
+        .iter()
+        .flat_map(|(colour, count)| std::iter::repeat_n(colour, count as usize))
+        .collect::<Vec<&str>>();
+    let size = (size as usize).min(cubes.len());
+
+    let hands = (0..draws)
+        .map(|_| {
+            // Shuffling just the front of the bag is enough to pick `size`
+            for i in 0..size {
+                let j = i + rng.next((cubes.len() - i) as u64) as usize;
+                cubes.swap(i, j);
+            }
+
+            cubes[..size].iter().map(|colour| (*colour, 1)).collect()
+        })
+        .collect();
+
+    Game { id, hands }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input_line;
+
+    #[test]
+    fn log_likelihood_works() {
+        let game = parse_input_line("Game 1: 1 red, 1 blue").unwrap();
+        let draws = Draws::new(&game, 10);
+
+        // Colours are in alphabetical order, so blue then red
+        assert_eq!(draws.log_likelihood(&[1, 0]), f64::NEG_INFINITY);
+        assert_eq!(draws.log_likelihood(&[1, 1]), 0.0);
+        // 2 * 1 ways out of C(3, 2) = 3
+        assert!((draws.log_likelihood(&[1, 2]) - (2.0f64 / 3.0).ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn single_colour_stays_at_minimum() {
+        let game = parse_input_line("Game 1: 3 red; 5 red").unwrap();
+        let estimate = Estimator { limit: 20 }.estimate(&game);
+
+        assert_eq!(estimate.bag, Cubes::from_iter([("red", 5)]));
+        assert_eq!(estimate.intervals["red"], Interval { low: 5, high: None });
+    }
+
+    #[test]
+    fn estimate_can_be_bigger_than_minimum() {
+        // A bag of 2 red and 1 blue gives 2/9 for these, 3 red and 1 blue
+        // gives 1/4, and 4 red gives 6/25
+        let game = parse_input_line("Game 1: 2 red; 1 red, 1 blue").unwrap();
+        let estimate = Estimator { limit: 20 }.estimate(&game);
+
+        assert_eq!(
+            game.minimum_bag(),
+            Cubes::from_iter([("red", 2), ("blue", 1)])
+        );
+        assert_eq!(estimate.bag, Cubes::from_iter([("red", 3), ("blue", 1)]));
+        assert!((estimate.log_likelihood - 0.25f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn search_matches_trying_every_bag() {
+        let bag = Cubes::from_iter([("red", 4), ("green", 2), ("blue", 3)]);
+        let game = sample_game(1, &bag, 6, 3, 5);
+        let draws = Draws::new(&game, 9);
+        let search = draws.search();
+
+        let mut best = f64::NEG_INFINITY;
+        let mut profiles = vec![vec![f64::NEG_INFINITY; 10]; 3];
+        for blue in draws.minimum[0]..=9 {
+            for green in draws.minimum[1]..=9 {
+                for red in draws.minimum[2]..=9 {
+                    let bag = [blue, green, red];
+                    let log_likelihood = draws.log_likelihood(&bag);
+                    best = best.max(log_likelihood);
+
+                    for (profile, count) in profiles.iter_mut().zip(bag) {
+                        let entry = &mut profile[count as usize];
+                        *entry = entry.max(log_likelihood);
+                    }
+                }
+            }
+        }
+
+        assert!((search.log_likelihood - best).abs() < 1e-9);
+        for (found, tried) in search
+            .profiles
+            .iter()
+            .flatten()
+            .zip(profiles.iter().flatten())
+        {
+            assert!(
+                found == tried || (found - tried).abs() < 1e-9,
+                "{} {}",
+                found,
+                tried
+            );
+        }
+    }
+
+    #[test]
+    fn many_colours_are_quick() {
+        let game = parse_input_line(
+            "Game 1: 1 red, 2 green, 1 blue, 3 cyan, 1 pink, 2 black; 2 white, 1 grey, 1 red",
+        )
+        .unwrap();
+        let estimate = Estimator { limit: 200 }.estimate(&game);
+
+        assert_eq!(estimate.intervals.len(), 8);
+        assert!(game.minimum_bag().fits_in(&estimate.bag));
+    }
+
+    #[test]
+    fn sample_game_works() {
+        let bag = Cubes::from_iter([("red", 12), ("green", 13), ("blue", 14)]);
+        let game = sample_game(4, &bag, 6, 10, 1);
+
+        assert_eq!(game.id, 4);
+        assert_eq!(game.hands.len(), 6);
+        assert!(game
+            .hands
+            .iter()
+            .all(|hand| hand.total() == 10 && hand.fits_in(&bag)));
+
+        assert_eq!(sample_game(4, &bag, 6, 10, 1), game);
+        assert_eq!(sample_game(1, &bag, 1, 50, 1).hands[0], bag);
+    }
+
+    #[test]
+    fn estimate_recovers_known_bag() {
+        let bag = Cubes::from_iter([("red", 3), ("green", 5), ("blue", 2)]);
+        let game = sample_game(1, &bag, 200, 4, 7);
+        let estimate = Estimator { limit: 30 }.estimate(&game);
+
+        for (colour, count) in bag.iter() {
+            let interval = estimate.intervals[colour];
+            assert!(interval.contains(count), "{} {}", colour, interval);
+            assert!(interval.high.is_some(), "{} {}", colour, interval);
+        }
+    }
+}
@@ -0,0 +1,278 @@
+pub mod inference;
+pub mod part1;
+pub mod part2;
+pub mod rules;
+
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fmt;
+
+// A count of cubes for each colour. Colours that aren't there count as 0, so
+// hands and bags can be compared even when they mention different colours.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub hands: Vec<Cubes>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameError {
+    // Not "Game N: ..."
+    BadLine { line: String },
+    // More of a colour than fits in a u32
+    TooManyCubes { colour: String },
+    // The power of the game's minimum bag doesn't fit in a u64
+    PowerTooLarge { id: u32 },
+    TotalTooLarge,
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::BadLine { line } => write!(f, "Invalid game: {:?}", line),
+            GameError::TooManyCubes { colour } => write!(f, "Too many {} cubes", colour),
+            GameError::PowerTooLarge { id } => write!(f, "Game {}: the power is too large", id),
+            GameError::TotalTooLarge => write!(f, "The total of the powers is too large"),
+        }
+    }
+}
+
+impl Cubes {
+    pub fn new() -> Cubes {
+        Cubes::default()
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        *self.counts.get(colour).unwrap_or(&0)
+    }
+
+    pub fn add(&mut self, colour: &str, count: u32) -> Result<(), GameError> {
+        let entry = self.counts.entry(colour.to_string()).or_insert(0);
+        *entry = entry.checked_add(count).ok_or(GameError::TooManyCubes {
+            colour: colour.to_string(),
+        })?;
+        Ok(())
+    }
+
+    // In alphabetical order
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(|colour| colour.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+
+    // Every count fits in a u32, so the total of them fits in a u64
+    pub fn total(&self) -> u64 {
+        self.counts.values().map(|count| *count as u64).sum()
+    }
+
+    // Could these cubes have come out of the bag?
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.iter().all(|(colour, count)| count <= bag.get(colour))
+    }
+
+    // The larger count of each colour from either set
+    pub fn max(&self, other: &Cubes) -> Cubes {
+        let mut output = self.clone();
+
+        for (colour, count) in other.iter() {
+            let entry = output.counts.entry(colour.to_string()).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+
+        output
+    }
+
+    // Product of the count of every colour we have, if it fits
+    pub fn power(&self) -> Option<u64> {
+        self.counts
+            .values()
+            .try_fold(1u64, |power, count| power.checked_mul(*count as u64))
+    }
+}
+
+// For counts that are known to be small, like a bag written out by hand.
+// Parsing goes through add, so too many cubes is an error there.
+impl<'a> FromIterator<(&'a str, u32)> for Cubes {
+    fn from_iter<T: IntoIterator<Item = (&'a str, u32)>>(iter: T) -> Self {
+        let mut cubes = Cubes::new();
+
+        for (colour, count) in iter {
+            cubes.add(colour, count).expect("Cubes should fit in a u32");
+        }
+
+        cubes
+    }
+}
+
+impl Game {
+    // The smallest bag that could have produced every hand
+    pub fn minimum_bag(&self) -> Cubes {
+        self.hands
+            .iter()
+            .fold(Cubes::new(), |bag, hand| bag.max(hand))
+    }
+}
+
+pub fn parse_input_line(line: &str) -> Result<Game, GameError> {
+    let bad_line = || GameError::BadLine {
+        line: line.to_string(),
+    };
+
+    let re = Regex::new(r"^Game (\d+):(.*)$").unwrap();
+    let captures = re.captures(line).ok_or_else(bad_line)?;
+    let id: u32 = captures
+        .get(1)
+        .unwrap()
+        .as_str()
+        .parse()
+        .map_err(|_| bad_line())?;
+    let hand = captures.get(2).unwrap().as_str();
+    let hands = hand
+        .split(";")
+        .map(parse_hand)
+        .collect::<Result<Vec<Cubes>, GameError>>()?;
+
+    Ok(Game { id, hands })
+}
+
+pub fn parse_hand(str: &str) -> Result<Cubes, GameError> {
+    let re = Regex::new(r"(\d+) (\w+)").unwrap();
+    let mut cubes = Cubes::new();
+
+    for cap in re.captures_iter(str) {
+        let colour = cap.get(2).unwrap().as_str();
+        let count: u32 =
+            cap.get(1)
+                .unwrap()
+                .as_str()
+                .parse()
+                .map_err(|_| GameError::TooManyCubes {
+                    colour: colour.to_string(),
+                })?;
+
+        cubes.add(colour, count)?;
+    }
+
+    Ok(cubes)
+}
+
+impl std::fmt::Display for Cubes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let counts = self
+            .iter()
+            .map(|(colour, count)| format!("{}: {}", colour, count))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, "{};", counts)
+    }
+}
+
+impl std::fmt::Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut hands = String::new();
+        for hand in &self.hands {
+            hands.push_str(&format!("{} ", hand));
+        }
+        write!(f, "Game {}: {}", self.id, hands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hand_works_single_color() {
+        let hand = parse_hand("1 red").unwrap();
+        assert_eq!(hand.get("red"), 1);
+        assert_eq!(hand.get("blue"), 0);
+        assert_eq!(hand.get("green"), 0);
+    }
+
+    #[test]
+    fn parse_hand_works_multiple_colors() {
+        let hand = parse_hand("1 red, 2 blue, 3 green").unwrap();
+        assert_eq!(hand.get("red"), 1);
+        assert_eq!(hand.get("blue"), 2);
+        assert_eq!(hand.get("green"), 3);
+    }
+
+    #[test]
+    fn parse_hand_works_any_color() {
+        let hand = parse_hand("4 yellow, 2 blue, 1 ultraviolet").unwrap();
+        assert_eq!(hand.get("yellow"), 4);
+        assert_eq!(hand.get("ultraviolet"), 1);
+        assert_eq!(
+            hand.colours().collect::<Vec<&str>>(),
+            vec!["blue", "ultraviolet", "yellow"]
+        );
+        assert_eq!(hand.total(), 7);
+    }
+
+    #[test]
+    fn fits_in_works() {
+        let bag = Cubes::from_iter([("red", 2), ("blue", 1)]);
+
+        assert!(Cubes::from_iter([("red", 2)]).fits_in(&bag));
+        assert!(!Cubes::from_iter([("red", 3)]).fits_in(&bag));
+        assert!(!Cubes::from_iter([("yellow", 1)]).fits_in(&bag));
+        assert!(Cubes::new().fits_in(&bag));
+    }
+
+    #[test]
+    fn minimum_bag_works() {
+        let game =
+            parse_input_line("Game 7: 3 blue, 4 red; 1 red, 2 pink, 6 blue; 2 pink").unwrap();
+        let bag = game.minimum_bag();
+
+        assert_eq!(
+            bag,
+            Cubes::from_iter([("blue", 6), ("pink", 2), ("red", 4)])
+        );
+        assert_eq!(bag.power(), Some(48));
+        assert_eq!(bag.to_string(), "blue: 6, pink: 2, red: 4;");
+    }
+
+    #[test]
+    fn too_many_cubes() {
+        assert_eq!(
+            parse_hand("4294967295 red, 1 red"),
+            Err(GameError::TooManyCubes {
+                colour: "red".to_string()
+            })
+        );
+        assert_eq!(
+            parse_hand("4294967296 red"),
+            Err(GameError::TooManyCubes {
+                colour: "red".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input_line("Game x: 1 red"),
+            Err(GameError::BadLine {
+                line: "Game x: 1 red".to_string()
+            })
+        );
+
+        let hand = parse_hand("4294967295 red, 4294967295 blue, 2 green").unwrap();
+        assert_eq!(hand.total(), 2 * 4294967295 + 2);
+        assert_eq!(hand.power(), None);
+        assert_eq!(
+            parse_hand("4294967295 red, 4294967295 blue")
+                .unwrap()
+                .power(),
+            Some(4294967295 * 4294967295)
+        );
+    }
+}
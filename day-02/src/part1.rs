@@ -0,0 +1,61 @@
+use crate::rules::Rules;
+use crate::{parse_input_line, Game, GameError};
+
+pub fn parse(input: &str) -> Result<Vec<Game>, GameError> {
+    input.lines().map(parse_input_line).collect()
+}
+
+pub fn solve(games: &[Game]) -> u32 {
+    let rules = Rules::standard();
+
+    games
+        .iter()
+        .filter(|game| rules.allows(game))
+        .map(|game| game.id)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rule;
+    use crate::Cubes;
+
+    fn one_of_each() -> Rules {
+        Rules {
+            rules: vec![Rule::Bag(Cubes::from_iter([
+                ("red", 1),
+                ("blue", 1),
+                ("green", 1),
+            ]))],
+        }
+    }
+
+    fn game_of(hands: Vec<Cubes>) -> Game {
+        Game { id: 1, hands }
+    }
+
+    #[test]
+    fn test_is_valid_hand() {
+        let rules = one_of_each();
+
+        let hand = Cubes::from_iter([("red", 1), ("blue", 1), ("green", 1)]);
+        assert!(rules.allows(&game_of(vec![hand])));
+
+        let hand = Cubes::from_iter([("red", 2), ("blue", 1), ("green", 1)]);
+        assert!(!rules.allows(&game_of(vec![hand])));
+
+        // A colour the bag doesn't have
+        let hand = Cubes::from_iter([("yellow", 1)]);
+        assert!(!rules.allows(&game_of(vec![hand])));
+    }
+
+    #[test]
+    fn test_is_game_valid() {
+        let game = game_of(vec![
+            Cubes::from_iter([("red", 2), ("blue", 1), ("green", 1)]),
+            Cubes::from_iter([("red", 1), ("blue", 1), ("green", 1)]),
+        ]);
+        assert!(!one_of_each().allows(&game));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{parse_input_line, Game, GameError};
+
+pub fn parse(input: &str) -> Result<Vec<Game>, GameError> {
+    input.lines().map(parse_input_line).collect()
+}
+
+fn calculate_power_set(game: &Game) -> Result<u64, GameError> {
+    game.minimum_bag()
+        .power()
+        .ok_or(GameError::PowerTooLarge { id: game.id })
+}
+
+pub fn solve(games: &[Game]) -> Result<u64, GameError> {
+    games.iter().try_fold(0u64, |total, game| {
+        total
+            .checked_add(calculate_power_set(game)?)
+            .ok_or(GameError::TotalTooLarge)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powerset_1() {
+        let result = calculate_power_set(
+            &parse_input_line("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap(),
+        );
+        assert_eq!(result, Ok(48));
+    }
+
+    #[test]
+    fn test_powerset_2() {
+        let result = calculate_power_set(
+            &parse_input_line("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                .unwrap(),
+        );
+        assert_eq!(result, Ok(12));
+    }
+    #[test]
+    fn test_powerset_3() {
+        let result = calculate_power_set(
+            &parse_input_line(
+                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, Ok(1560));
+    }
+
+    #[test]
+    fn power_too_large() {
+        let games = parse(
+            "Game 1: 4294967295 red, 4294967295 blue, 2 green
+Game 2: 4294967295 red, 4294967295 blue; 1 green
+Game 3: 4294967295 red, 4294967295 blue",
+        )
+        .unwrap();
+
+        assert_eq!(solve(&games), Err(GameError::PowerTooLarge { id: 1 }));
+        assert_eq!(solve(&games[1..]), Err(GameError::TotalTooLarge));
+        assert_eq!(solve(&games[2..]), Ok(4294967295 * 4294967295));
+    }
+}
@@ -0,0 +1,247 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{parse_hand, Cubes, Game};
+
+// -------- RULES ---------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    // Every draw has to fit in the bag
+    Bag(Cubes),
+    // No draw has more than this many cubes in total
+    MaxTotal(u32),
+    // At least one draw has some of this colour in it
+    Contains(String),
+}
+
+// A game breaking a rule. `draw` is the index into the game's hands, or None
+// for rules that are about the game as a whole.
+#[derive(Debug, PartialEq)]
+pub struct Failure<'a> {
+    pub game: u32,
+    pub rule: &'a Rule,
+    pub draw: Option<usize>,
+}
+
+impl Rule {
+    pub fn failures<'a>(&'a self, game: &Game) -> Vec<Failure<'a>> {
+        let failure = |draw| Failure {
+            game: game.id,
+            rule: self,
+            draw,
+        };
+
+        match self {
+            Rule::Bag(bag) => game
+                .hands
+                .iter()
+                .enumerate()
+                .filter(|(_, hand)| !hand.fits_in(bag))
+                .map(|(draw, _)| failure(Some(draw)))
+                .collect(),
+            Rule::MaxTotal(max) => game
+                .hands
+                .iter()
+                .enumerate()
+                .filter(|(_, hand)| hand.total() > *max as u64)
+                .map(|(draw, _)| failure(Some(draw)))
+                .collect(),
+            Rule::Contains(colour) => {
+                if game.hands.iter().any(|hand| hand.get(colour) > 0) {
+                    vec![]
+                } else {
+                    vec![failure(None)]
+                }
+            }
+        }
+    }
+}
+
+// The same format as the config file, one of:
+//
+//   bag 12 red, 13 green, 14 blue
+//   max-total 20
+//   contains blue
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .trim()
+            .split_once(' ')
+            .ok_or(format!("Invalid rule: {:?}", s))?;
+        let value = value.trim();
+
+        match name {
+            "bag" => {
+                let bag = parse_hand(value).map_err(|err| err.to_string())?;
+                if bag.colours().next().is_none() {
+                    return Err(format!("Invalid bag: {:?}", value));
+                }
+                Ok(Rule::Bag(bag))
+            }
+            "max-total" => Ok(Rule::MaxTotal(
+                value
+                    .parse()
+                    .map_err(|_| format!("Invalid total: {:?}", value))?,
+            )),
+            "contains" => Ok(Rule::Contains(value.to_string())),
+            _ => Err(format!("Unknown rule: {:?}", name)),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Bag(bag) => {
+                let counts = bag
+                    .iter()
+                    .map(|(colour, count)| format!("{} {}", count, colour))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "bag {}", counts)
+            }
+            Rule::MaxTotal(max) => write!(f, "max-total {}", max),
+            Rule::Contains(colour) => write!(f, "contains {}", colour),
+        }
+    }
+}
+
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.draw {
+            Some(draw) => write!(
+                f,
+                "Game {} fails {} on draw {}",
+                self.game,
+                self.rule,
+                draw + 1
+            ),
+            None => write!(f, "Game {} fails {}", self.game, self.rule),
+        }
+    }
+}
+
+// -------- RULE SETS ---------
+
+// A game is possible if it breaks none of the rules
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rules {
+    pub rules: Vec<Rule>,
+}
+
+impl Rules {
+    // Part 1, just the bag
+    pub fn standard() -> Rules {
+        Rules {
+            rules: vec![Rule::Bag(Cubes::from_iter([
+                ("red", 12),
+                ("green", 13),
+                ("blue", 14),
+            ]))],
+        }
+    }
+
+    pub fn failures<'a>(&'a self, game: &Game) -> Vec<Failure<'a>> {
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.failures(game))
+            .collect()
+    }
+
+    pub fn allows(&self, game: &Game) -> bool {
+        self.failures(game).is_empty()
+    }
+}
+
+// One rule per line, with blank lines and lines starting with # skipped
+impl FromStr for Rules {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules = s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| line.parse::<Rule>())
+            .collect::<Result<Vec<Rule>, String>>()?;
+
+        Ok(Rules { rules })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input_line;
+
+    #[test]
+    fn parse_rules_works() {
+        let rules = "# The part 1 bag
+bag 12 red, 13 green, 14 blue
+
+max-total 20
+contains blue"
+            .parse::<Rules>()
+            .unwrap();
+
+        assert_eq!(
+            rules.rules,
+            vec![
+                Rules::standard().rules[0].clone(),
+                Rule::MaxTotal(20),
+                Rule::Contains("blue".to_string()),
+            ]
+        );
+        assert_eq!(rules.rules[0].to_string(), "bag 14 blue, 13 green, 12 red");
+
+        assert!("bag nothing".parse::<Rule>().is_err());
+        assert!("max-total lots".parse::<Rule>().is_err());
+        assert!("min-total 3".parse::<Rule>().is_err());
+        assert!("contains".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn failures_report_the_draw() {
+        let game = parse_input_line(
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        )
+        .unwrap();
+        let rules = Rules {
+            rules: vec![
+                Rules::standard().rules[0].clone(),
+                Rule::MaxTotal(20),
+                Rule::Contains("yellow".to_string()),
+            ],
+        };
+
+        let failures = rules
+            .failures(&game)
+            .iter()
+            .map(|failure| failure.to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            failures,
+            vec![
+                "Game 3 fails bag 14 blue, 13 green, 12 red on draw 1",
+                "Game 3 fails max-total 20 on draw 1",
+                "Game 3 fails max-total 20 on draw 2",
+                "Game 3 fails contains yellow",
+            ]
+        );
+        assert!(!rules.allows(&game));
+    }
+
+    #[test]
+    fn allows_works() {
+        let game =
+            parse_input_line("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+
+        assert!(Rules::standard().allows(&game));
+        assert!("contains blue".parse::<Rules>().unwrap().allows(&game));
+        assert!(!"max-total 8".parse::<Rules>().unwrap().allows(&game));
+    }
+}
@@ -0,0 +1,94 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use regex::Regex;
+
+use day_03::generate;
+use day_03::rules::GearRule;
+use day_03::Schematic;
+
+// The original part 2, which checks every gear against every number
+mod pairwise {
+    use super::*;
+
+    fn is_egnine_number_adjacent(
+        gear: (usize, usize),
+        engine_number: (i32, usize, usize, usize),
+    ) -> bool {
+        let (gear_row, gear_col) = gear;
+        let (_part_number, engine_row, engine_col_start, engine_col_end) = engine_number;
+
+        let engine_row_min = engine_row.saturating_sub(1);
+        let engine_col_min = engine_col_start.saturating_sub(1);
+
+        gear_row >= engine_row_min
+            && gear_row <= engine_row + 1
+            && gear_col >= engine_col_min
+            && gear_col <= engine_col_end
+    }
+
+    pub fn part2(input: &str) -> u64 {
+        let mut gears: Vec<(usize, usize)> = Vec::new();
+        let mut engine_numbers: Vec<(i32, usize, usize, usize)> = Vec::new();
+
+        input.lines().enumerate().for_each(|(row, line)| {
+            let re = Regex::new(r"(\d+)|(\*)").unwrap();
+
+            re.captures_iter(line).for_each(|cap| {
+                if let Some(digit_match) = cap.get(1) {
+                    let part_number = digit_match.as_str().parse::<i32>().unwrap();
+                    engine_numbers.push((part_number, row, digit_match.start(), digit_match.end()));
+                }
+
+                if let Some(gear_match) = cap.get(2) {
+                    gears.push((row, gear_match.start()));
+                }
+            });
+        });
+
+        gears
+            .iter()
+            .filter_map(|gear| {
+                let adjacent_engine_numbers: Vec<i32> = engine_numbers
+                    .iter()
+                    .filter(|engine_number| is_egnine_number_adjacent(*gear, **engine_number))
+                    .map(|engine_number| engine_number.0)
+                    .collect();
+
+                if adjacent_engine_numbers.len() == 2 {
+                    Some(adjacent_engine_numbers[0] as u64 * adjacent_engine_numbers[1] as u64)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+fn indexed(input: &str) -> u64 {
+    let schematic = Schematic::parse(input).expect("Schematic should be valid");
+    GearRule::standard()
+        .total(&schematic)
+        .expect("Total should fit in a u64")
+}
+
+fn gears(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gears");
+    group.sample_size(10);
+
+    for size in [100, 300, 1000] {
+        let input = generate::schematic(size, 2023);
+
+        assert_eq!(pairwise::part2(&input), indexed(&input));
+
+        group.bench_with_input(BenchmarkId::new("pairwise", size), &input, |b, input| {
+            b.iter(|| pairwise::part2(input))
+        });
+        group.bench_with_input(BenchmarkId::new("indexed", size), &input, |b, input| {
+            b.iter(|| indexed(input))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, gears);
+criterion_main!(benches);
@@ -0,0 +1,123 @@
+use day_03::rules::{GearRule, NeighbourCount, Reducer, SymbolSet};
+use day_03::Schematic;
+
+// Prints every symbol a gear rule applies to, the numbers next to it, and
+// what they combine to. With no options this is the part 2 rule.
+//
+// Usage: cargo run --bin gears -- [--symbols *#|any] [--neighbours 2|2+]
+//                                 [--reducer product|sum|min|max] [input file]
+
+fn main() {
+    let (rule, path) = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    let input = match path {
+        Some(path) => std::fs::read_to_string(path).expect("Should be able to read input file"),
+        None => include_str!("./input.txt").to_string(),
+    };
+
+    let schematic = match Schematic::parse(&input) {
+        Ok(schematic) => schematic,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let breakdown = match rule.breakdown(&schematic) {
+        Ok(breakdown) => breakdown,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    for symbol in &breakdown {
+        let numbers = symbol
+            .numbers
+            .iter()
+            .map(|n| n.value.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let (row, col) = symbol.symbol.pos;
+        let value = match symbol.value {
+            Some(value) => value.to_string(),
+            None => format!("not a gear, needs {} numbers", rule.neighbours),
+        };
+
+        println!(
+            "{} at {},{} [{}] -> {}",
+            symbol.symbol.ch, row, col, numbers, value
+        );
+    }
+
+    match rule.total(&schematic) {
+        Ok(total) => println!("Result: {}", total),
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn parse_args(
+    args: impl IntoIterator<Item = String>,
+) -> Result<(GearRule, Option<String>), String> {
+    let mut rule = GearRule::standard();
+    let mut path = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+
+        match arg.as_str() {
+            "--symbols" => rule.symbols = value()?.parse::<SymbolSet>()?,
+            "--neighbours" => rule.neighbours = value()?.parse::<NeighbourCount>()?,
+            "--reducer" => rule.reducer = value()?.parse::<Reducer>()?,
+            _ => path = Some(arg),
+        }
+    }
+
+    Ok((rule, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_args_works() {
+        let result = parse_args(
+            [
+                "--symbols",
+                "*#",
+                "--neighbours",
+                "1+",
+                "--reducer",
+                "sum",
+                "other.txt",
+            ]
+            .map(String::from),
+        );
+
+        assert_eq!(
+            result,
+            Ok((
+                GearRule {
+                    symbols: SymbolSet::Only(vec!['*', '#']),
+                    neighbours: NeighbourCount::AtLeast(1),
+                    reducer: Reducer::Sum,
+                },
+                Some("other.txt".to_string())
+            ))
+        );
+
+        assert_eq!(parse_args(Vec::new()), Ok((GearRule::standard(), None)));
+        assert!(parse_args(["--reducer"].map(String::from)).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+use day_03::render::{render, Format};
+use day_03::rules::{GearRule, PartRule};
+use day_03::Schematic;
+
+// Draws the schematic with part numbers, numbers with no symbol, and gears
+// with their ratios picked out in different colours.
+//
+// Usage: cargo run --bin render -- [--html] [input file]
+
+fn main() {
+    let mut format = Format::Ansi;
+    let mut path: Option<String> = None;
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--html" => format = Format::Html,
+            _ => path = Some(arg),
+        }
+    }
+
+    let input = match path {
+        Some(path) => std::fs::read_to_string(path).expect("Should be able to read input file"),
+        None => include_str!("./input.txt").to_string(),
+    };
+
+    let schematic = match Schematic::parse(&input) {
+        Ok(schematic) => schematic,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+    match render(
+        &schematic,
+        &PartRule::standard(),
+        &GearRule::standard(),
+        format,
+    ) {
+        Ok(output) => print!("{}", output),
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use rng::Rng;
+
+// Random schematics for benchmarking. The same seed always gives the same
+// schematic.
+pub fn schematic(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+
+    let symbols = ['*', '#', '+', '$', '/', '=', '%', '@', '&', '-'];
+    let mut output = String::with_capacity((size + 1) * size);
+
+    for _ in 0..size {
+        let mut row = String::with_capacity(size);
+
+        while row.len() < size {
+            match rng.next(10) {
+                // Numbers of up to three digits, with a gap after them so
+                // they don't run into the next one
+                0..=2 => {
+                    let digits = 1 + rng.next(3) as usize;
+                    let number = (1 + rng.next(999)).to_string();
+                    row.push_str(&number[..digits.min(number.len())]);
+                    row.push('.');
+                }
+                3 => row.push(symbols[rng.next(symbols.len() as u64) as usize]),
+                _ => row.push('.'),
+            }
+        }
+
+        row.truncate(size);
+        output.push_str(&row);
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Schematic;
+
+    #[test]
+    fn schematic_is_square_and_repeatable() {
+        let input = schematic(50, 7);
+
+        assert_eq!(input, schematic(50, 7));
+        assert_eq!(input.lines().count(), 50);
+        assert!(input.lines().all(|line| line.len() == 50));
+
+        let parsed = Schematic::parse(&input).unwrap();
+        assert!(!parsed.numbers.is_empty());
+        assert!(!parsed.symbols.is_empty());
+    }
+}
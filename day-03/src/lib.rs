@@ -0,0 +1,292 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod render;
+pub mod rules;
+
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartNumber {
+    pub value: u32,
+    pub row: usize,
+    // Columns the digits cover
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub ch: char,
+    // (row, col)
+    pub pos: (usize, usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchematicError {
+    // The digits don't fit in a u32
+    NumberTooLarge { line: usize, column: usize },
+}
+
+impl fmt::Display for SchematicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchematicError::NumberTooLarge { line, column } => write!(
+                f,
+                "Line {}: the number at column {} is too large",
+                line, column
+            ),
+        }
+    }
+}
+
+// The schematic as a grid of characters, plus the numbers and symbols found in
+// it. Every cell also remembers which number or symbol is in it, so finding
+// what is next to something is just a lookup per neighbouring cell.
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Vec<Vec<char>>,
+    pub numbers: Vec<PartNumber>,
+    pub symbols: Vec<Symbol>,
+    number_index: Vec<Vec<Option<usize>>>,
+    symbol_index: Vec<Vec<Option<usize>>>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Schematic, SchematicError> {
+        let grid = input
+            .lines()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+        let mut number_index = Vec::new();
+        let mut symbol_index = Vec::new();
+
+        for (row, line) in grid.iter().enumerate() {
+            let mut number_row = vec![None; line.len()];
+            let mut symbol_row = vec![None; line.len()];
+
+            let mut col = 0;
+            while col < line.len() {
+                let ch = line[col];
+
+                if ch.is_ascii_digit() {
+                    let start = col;
+                    let mut value = Some(0u32);
+
+                    while col < line.len() && line[col].is_ascii_digit() {
+                        let digit = line[col].to_digit(10).expect("Should be a digit");
+                        value = value
+                            .and_then(|value| value.checked_mul(10))
+                            .and_then(|value| value.checked_add(digit));
+                        number_row[col] = Some(numbers.len());
+                        col += 1;
+                    }
+
+                    let value = value.ok_or(SchematicError::NumberTooLarge {
+                        line: row + 1,
+                        column: start + 1,
+                    })?;
+
+                    numbers.push(PartNumber {
+                        value,
+                        row,
+                        span: start..col,
+                    });
+                    continue;
+                }
+
+                // Anything that isn't a number or . is a symbol
+                if ch != '.' {
+                    symbol_row[col] = Some(symbols.len());
+                    symbols.push(Symbol {
+                        ch,
+                        pos: (row, col),
+                    });
+                }
+
+                col += 1;
+            }
+
+            number_index.push(number_row);
+            symbol_index.push(symbol_row);
+        }
+
+        Ok(Schematic {
+            grid,
+            numbers,
+            symbols,
+            number_index,
+            symbol_index,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<char> {
+        self.grid.get(row)?.get(col).copied()
+    }
+
+    pub fn number_at(&self, row: usize, col: usize) -> Option<&PartNumber> {
+        let id = (*self.number_index.get(row)?.get(col)?)?;
+        Some(&self.numbers[id])
+    }
+
+    pub fn symbol_at(&self, row: usize, col: usize) -> Option<&Symbol> {
+        let id = (*self.symbol_index.get(row)?.get(col)?)?;
+        Some(&self.symbols[id])
+    }
+
+    pub fn symbols_adjacent_to(&self, number: &PartNumber) -> Vec<&Symbol> {
+        neighbours(number.row, number.span.clone())
+            .filter_map(|(row, col)| self.symbol_at(row, col))
+            .collect()
+    }
+
+    pub fn numbers_adjacent_to(&self, symbol: &Symbol) -> Vec<&PartNumber> {
+        let (row, col) = symbol.pos;
+        let mut ids: Vec<usize> = Vec::new();
+
+        // A number can touch the symbol in more than one cell, only count it once
+        for (row, col) in neighbours(row, col..col + 1) {
+            if let Some(Some(id)) = self.number_index.get(row).and_then(|r| r.get(col)) {
+                if !ids.contains(id) {
+                    ids.push(*id);
+                }
+            }
+        }
+
+        ids.iter().map(|id| &self.numbers[*id]).collect()
+    }
+}
+
+// All cells touching the given columns of a row, including diagonals, but
+// not the cells themselves
+fn neighbours(row: usize, span: Range<usize>) -> impl Iterator<Item = (usize, usize)> {
+    let rows = row.saturating_sub(1)..row + 2;
+    let cols = span.start.saturating_sub(1)..span.end + 1;
+
+    rows.flat_map(move |r| cols.clone().map(move |c| (r, c)))
+        .filter(move |(r, c)| *r != row || !span.contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn parse_works() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+
+        assert_eq!(schematic.height(), 10);
+        assert_eq!(schematic.width(), 10);
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(schematic.symbols.len(), 6);
+
+        assert_eq!(
+            schematic.numbers[0],
+            PartNumber {
+                value: 467,
+                row: 0,
+                span: 0..3
+            }
+        );
+        assert_eq!(
+            schematic.symbols[0],
+            Symbol {
+                ch: '*',
+                pos: (1, 3)
+            }
+        );
+    }
+
+    #[test]
+    fn lookups_work() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+
+        assert_eq!(schematic.get(2, 2), Some('3'));
+        assert_eq!(schematic.get(20, 2), None);
+        assert_eq!(schematic.number_at(2, 3).map(|n| n.value), Some(35));
+        assert_eq!(schematic.number_at(2, 4), None);
+        assert_eq!(schematic.symbol_at(3, 6).map(|s| s.ch), Some('#'));
+    }
+
+    #[test]
+    fn symbols_adjacent_to_works() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+
+        let number = schematic.number_at(0, 0).unwrap();
+        let symbols = schematic.symbols_adjacent_to(number);
+        assert_eq!(symbols, vec![&schematic.symbols[0]]);
+
+        // 114 isn't next to anything
+        let number = schematic.number_at(0, 5).unwrap();
+        assert!(schematic.symbols_adjacent_to(number).is_empty());
+    }
+
+    #[test]
+    fn numbers_adjacent_to_works() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+
+        let symbol = schematic.symbol_at(1, 3).unwrap();
+        let values = schematic
+            .numbers_adjacent_to(symbol)
+            .iter()
+            .map(|n| n.value)
+            .collect::<Vec<u32>>();
+        assert_eq!(values, vec![467, 35]);
+
+        // 617 touches the * on its own row only once
+        let symbol = schematic.symbol_at(4, 3).unwrap();
+        let values = schematic
+            .numbers_adjacent_to(symbol)
+            .iter()
+            .map(|n| n.value)
+            .collect::<Vec<u32>>();
+        assert_eq!(values, vec![617]);
+    }
+
+    #[test]
+    fn numbers_at_the_edges() {
+        let schematic = Schematic::parse("12\n*.\n.3").unwrap();
+
+        let symbol = schematic.symbol_at(1, 0).unwrap();
+        let values = schematic
+            .numbers_adjacent_to(symbol)
+            .iter()
+            .map(|n| n.value)
+            .collect::<Vec<u32>>();
+        assert_eq!(values, vec![12, 3]);
+    }
+
+    #[test]
+    fn numbers_that_are_too_large() {
+        assert_eq!(
+            Schematic::parse("4294967295*").map(|schematic| schematic.numbers[0].value),
+            Ok(u32::MAX)
+        );
+        assert_eq!(
+            Schematic::parse("..*\n.4294967296").unwrap_err(),
+            SchematicError::NumberTooLarge { line: 2, column: 2 }
+        );
+    }
+}
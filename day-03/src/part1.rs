@@ -0,0 +1,11 @@
+use crate::rules::{PartError, PartRule};
+use crate::{Schematic, SchematicError};
+
+pub fn parse(input: &str) -> Result<Schematic, SchematicError> {
+    Schematic::parse(input)
+}
+
+// A part number is any number with a symbol next to it
+pub fn solve(schematic: &Schematic) -> Result<u64, PartError> {
+    PartRule::standard().total(schematic)
+}
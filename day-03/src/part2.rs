@@ -0,0 +1,11 @@
+use crate::rules::{GearError, GearRule};
+use crate::{Schematic, SchematicError};
+
+pub fn parse(input: &str) -> Result<Schematic, SchematicError> {
+    Schematic::parse(input)
+}
+
+// A gear is a * next to exactly two part numbers
+pub fn solve(schematic: &Schematic) -> Result<u64, GearError> {
+    GearRule::standard().total(schematic)
+}
@@ -0,0 +1,246 @@
+use crate::rules::{GearError, GearRule, PartRule};
+use crate::Schematic;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Ansi,
+    Html,
+}
+
+// What each cell of the schematic turned out to be
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Class {
+    Plain,
+    // Numbers next to a symbol
+    Part,
+    // Numbers with no symbol next to them
+    Unattached,
+    Symbol,
+    Gear,
+    // Part numbers next to a gear
+    Ratio,
+}
+
+impl Class {
+    fn ansi(&self) -> Option<&'static str> {
+        match self {
+            Class::Plain => None,
+            Class::Part => Some("\x1b[32m"),
+            Class::Unattached => Some("\x1b[31m"),
+            Class::Symbol => Some("\x1b[1m"),
+            Class::Gear => Some("\x1b[1;33m"),
+            Class::Ratio => Some("\x1b[1;36m"),
+        }
+    }
+
+    fn css(&self) -> Option<&'static str> {
+        match self {
+            Class::Plain => None,
+            Class::Part => Some("part"),
+            Class::Unattached => Some("unattached"),
+            Class::Symbol => Some("symbol"),
+            Class::Gear => Some("gear"),
+            Class::Ratio => Some("ratio"),
+        }
+    }
+}
+
+const STYLE: &str = "<style>
+.schematic { color: #888; }
+.schematic .part { color: #2a2; }
+.schematic .unattached { color: #d33; }
+.schematic .symbol { color: #000; font-weight: bold; }
+.schematic .gear { background: #fd3; color: #000; font-weight: bold; }
+.schematic .ratio { background: #9df; color: #000; }
+</style>
+";
+
+pub fn classify(
+    schematic: &Schematic,
+    parts: &PartRule,
+    gears: &GearRule,
+) -> Result<Vec<Vec<Class>>, GearError> {
+    let mut classes = (0..schematic.height())
+        .map(|row| {
+            (0..schematic.width())
+                .take_while(|col| schematic.get(row, *col).is_some())
+                .map(|_| Class::Plain)
+                .collect::<Vec<Class>>()
+        })
+        .collect::<Vec<Vec<Class>>>();
+
+    for symbol in &schematic.symbols {
+        let (row, col) = symbol.pos;
+        classes[row][col] = Class::Symbol;
+    }
+
+    for number in &schematic.numbers {
+        let class = if parts.is_part_number(schematic, number) {
+            Class::Part
+        } else {
+            Class::Unattached
+        };
+
+        for col in number.span.clone() {
+            classes[number.row][col] = class;
+        }
+    }
+
+    // Gears go last so they win over everything else
+    for gear in gears.breakdown(schematic)? {
+        if gear.value.is_none() {
+            continue;
+        }
+
+        let (row, col) = gear.symbol.pos;
+        classes[row][col] = Class::Gear;
+
+        for number in gear.numbers {
+            for col in number.span.clone() {
+                classes[number.row][col] = Class::Ratio;
+            }
+        }
+    }
+
+    Ok(classes)
+}
+
+pub fn render(
+    schematic: &Schematic,
+    parts: &PartRule,
+    gears: &GearRule,
+    format: Format,
+) -> Result<String, GearError> {
+    let classes = classify(schematic, parts, gears)?;
+    let mut output = String::new();
+
+    if format == Format::Html {
+        output += STYLE;
+        output += "<pre class=\"schematic\">\n";
+    }
+
+    for (row, row_classes) in classes.iter().enumerate() {
+        let mut col = 0;
+
+        // Group runs of the same class so a number is a single span
+        while col < row_classes.len() {
+            let class = row_classes[col];
+            let start = col;
+
+            while col < row_classes.len() && row_classes[col] == class {
+                col += 1;
+            }
+
+            let text = (start..col)
+                .map(|c| schematic.get(row, c).expect("Should be a cell"))
+                .collect::<String>();
+
+            output += &match format {
+                Format::Ansi => match class.ansi() {
+                    Some(code) => format!("{}{}\x1b[0m", code, text),
+                    None => text,
+                },
+                Format::Html => match class.css() {
+                    Some(css) => format!("<span class=\"{}\">{}</span>", css, escape(&text)),
+                    None => escape(&text),
+                },
+            };
+        }
+
+        output += "\n";
+    }
+
+    if format == Format::Html {
+        output += "</pre>\n";
+    }
+
+    Ok(output)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    fn render_example(format: Format) -> String {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+        render(
+            &schematic,
+            &PartRule::standard(),
+            &GearRule::standard(),
+            format,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn classify_works() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+        let classes = classify(&schematic, &PartRule::standard(), &GearRule::standard()).unwrap();
+
+        assert_eq!(classes[0][0], Class::Ratio);
+        assert_eq!(classes[0][3], Class::Plain);
+        assert_eq!(classes[0][5], Class::Unattached);
+        assert_eq!(classes[1][3], Class::Gear);
+        assert_eq!(classes[2][6], Class::Part);
+        assert_eq!(classes[3][6], Class::Symbol);
+        // Only one number, so not a gear
+        assert_eq!(classes[4][3], Class::Symbol);
+        assert_eq!(classes[4][0], Class::Part);
+    }
+
+    #[test]
+    fn render_ansi_works() {
+        let output = render_example(Format::Ansi);
+        let mut lines = output.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("\x1b[1;36m467\x1b[0m..\x1b[31m114\x1b[0m..")
+        );
+        assert_eq!(lines.next(), Some("...\x1b[1;33m*\x1b[0m......"));
+    }
+
+    #[test]
+    fn render_html_works() {
+        let output = render_example(Format::Html);
+
+        assert!(output.starts_with("<style>"));
+        assert!(output.contains(
+            "<pre class=\"schematic\">\n<span class=\"ratio\">467</span>..<span class=\"unattached\">114</span>..\n"
+        ));
+        assert!(output.ends_with("</pre>\n"));
+    }
+
+    #[test]
+    fn render_html_escapes_symbols() {
+        let schematic = Schematic::parse("1&<").unwrap();
+        let output = render(
+            &schematic,
+            &PartRule::standard(),
+            &GearRule::standard(),
+            Format::Html,
+        )
+        .unwrap();
+
+        assert!(
+            output.contains("<span class=\"part\">1</span><span class=\"symbol\">&amp;&lt;</span>")
+        );
+    }
+}
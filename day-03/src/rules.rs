@@ -0,0 +1,392 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{PartNumber, Schematic, Symbol};
+
+// -------- SYMBOL SETS ---------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolSet {
+    // Anything that isn't a digit or a .
+    Any,
+    Only(Vec<char>),
+}
+
+impl SymbolSet {
+    pub fn contains(&self, ch: char) -> bool {
+        match self {
+            SymbolSet::Any => true,
+            SymbolSet::Only(chars) => chars.contains(&ch),
+        }
+    }
+}
+
+// "any" or the symbols themselves, e.g. "*#"
+impl FromStr for SymbolSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "any" {
+            return Ok(SymbolSet::Any);
+        }
+
+        if s.is_empty() || s.chars().any(|c| c.is_ascii_digit() || c == '.') {
+            return Err(format!("Invalid symbol set: {:?}", s));
+        }
+
+        Ok(SymbolSet::Only(s.chars().collect()))
+    }
+}
+
+// -------- NEIGHBOUR COUNTS ---------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NeighbourCount {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl NeighbourCount {
+    pub fn matches(&self, count: usize) -> bool {
+        match self {
+            NeighbourCount::Exactly(n) => count == *n,
+            NeighbourCount::AtLeast(n) => count >= *n,
+        }
+    }
+}
+
+// "2" for exactly two, "2+" for two or more
+impl FromStr for NeighbourCount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid neighbour count: {:?}", s);
+
+        match s.strip_suffix('+') {
+            Some(n) => Ok(NeighbourCount::AtLeast(n.parse().map_err(|_| invalid())?)),
+            None => Ok(NeighbourCount::Exactly(s.parse().map_err(|_| invalid())?)),
+        }
+    }
+}
+
+impl fmt::Display for NeighbourCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeighbourCount::Exactly(n) => write!(f, "{}", n),
+            NeighbourCount::AtLeast(n) => write!(f, "{}+", n),
+        }
+    }
+}
+
+// -------- REDUCERS ---------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reducer {
+    Product,
+    Sum,
+    Min,
+    Max,
+}
+
+impl Reducer {
+    // None if the result doesn't fit in a u64
+    pub fn reduce(&self, values: &[u32]) -> Option<u64> {
+        let mut values = values.iter().map(|v| *v as u64);
+
+        match self {
+            Reducer::Product => values.try_fold(1u64, |a, b| a.checked_mul(b)),
+            Reducer::Sum => values.try_fold(0u64, |a, b| a.checked_add(b)),
+            Reducer::Min => Some(values.min().unwrap_or(0)),
+            Reducer::Max => Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+impl FromStr for Reducer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Reducer::Product),
+            "sum" => Ok(Reducer::Sum),
+            "min" => Ok(Reducer::Min),
+            "max" => Ok(Reducer::Max),
+            _ => Err(format!("Invalid reducer: {:?}", s)),
+        }
+    }
+}
+
+// -------- RULES ---------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartError {
+    TotalTooLarge,
+}
+
+impl fmt::Display for PartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartError::TotalTooLarge => write!(f, "The total of the part numbers is too large"),
+        }
+    }
+}
+
+// Which symbols make a number a part number
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartRule {
+    pub symbols: SymbolSet,
+}
+
+impl PartRule {
+    // Part 1, any symbol counts
+    pub fn standard() -> PartRule {
+        PartRule {
+            symbols: SymbolSet::Any,
+        }
+    }
+
+    pub fn is_part_number(&self, schematic: &Schematic, number: &PartNumber) -> bool {
+        schematic
+            .symbols_adjacent_to(number)
+            .iter()
+            .any(|symbol| self.symbols.contains(symbol.ch))
+    }
+
+    pub fn part_numbers<'a>(&self, schematic: &'a Schematic) -> Vec<&'a PartNumber> {
+        schematic
+            .numbers
+            .iter()
+            .filter(|number| self.is_part_number(schematic, number))
+            .collect()
+    }
+
+    pub fn total(&self, schematic: &Schematic) -> Result<u64, PartError> {
+        self.part_numbers(schematic)
+            .iter()
+            .try_fold(0u64, |total, number| total.checked_add(number.value as u64))
+            .ok_or(PartError::TotalTooLarge)
+    }
+}
+
+// Which symbols are gears, how many numbers they need next to them, and how
+// to combine those numbers
+#[derive(Debug, Clone, PartialEq)]
+pub struct GearRule {
+    pub symbols: SymbolSet,
+    pub neighbours: NeighbourCount,
+    pub reducer: Reducer,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GearError {
+    // The numbers next to the gear don't combine into a u64
+    ValueTooLarge { row: usize, col: usize },
+    TotalTooLarge,
+}
+
+impl fmt::Display for GearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GearError::ValueTooLarge { row, col } => {
+                write!(f, "The gear at {},{} is too large", row, col)
+            }
+            GearError::TotalTooLarge => write!(f, "The total of the gears is too large"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SymbolBreakdown<'a> {
+    pub symbol: &'a Symbol,
+    pub numbers: Vec<&'a PartNumber>,
+    // None if the symbol doesn't have the right number of neighbours
+    pub value: Option<u64>,
+}
+
+impl GearRule {
+    // Part 2, a * next to exactly two numbers multiplied together
+    pub fn standard() -> GearRule {
+        GearRule {
+            symbols: SymbolSet::Only(vec!['*']),
+            neighbours: NeighbourCount::Exactly(2),
+            reducer: Reducer::Product,
+        }
+    }
+
+    // Every symbol the rule could apply to, whether it ended up a gear or not
+    pub fn breakdown<'a>(
+        &self,
+        schematic: &'a Schematic,
+    ) -> Result<Vec<SymbolBreakdown<'a>>, GearError> {
+        schematic
+            .symbols
+            .iter()
+            .filter(|symbol| self.symbols.contains(symbol.ch))
+            .map(|symbol| {
+                let numbers = schematic.numbers_adjacent_to(symbol);
+
+                let value = if self.neighbours.matches(numbers.len()) {
+                    let values = numbers.iter().map(|n| n.value).collect::<Vec<u32>>();
+                    let (row, col) = symbol.pos;
+                    Some(
+                        self.reducer
+                            .reduce(&values)
+                            .ok_or(GearError::ValueTooLarge { row, col })?,
+                    )
+                } else {
+                    None
+                };
+
+                Ok(SymbolBreakdown {
+                    symbol,
+                    numbers,
+                    value,
+                })
+            })
+            .collect()
+    }
+
+    pub fn total(&self, schematic: &Schematic) -> Result<u64, GearError> {
+        self.breakdown(schematic)?
+            .iter()
+            .filter_map(|breakdown| breakdown.value)
+            .try_fold(0u64, |total, value| total.checked_add(value))
+            .ok_or(GearError::TotalTooLarge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn parse_rules_works() {
+        assert_eq!("any".parse(), Ok(SymbolSet::Any));
+        assert_eq!("*#".parse(), Ok(SymbolSet::Only(vec!['*', '#'])));
+        assert!("*.".parse::<SymbolSet>().is_err());
+
+        assert_eq!("2".parse(), Ok(NeighbourCount::Exactly(2)));
+        assert_eq!("1+".parse(), Ok(NeighbourCount::AtLeast(1)));
+        assert!("two".parse::<NeighbourCount>().is_err());
+
+        assert_eq!("sum".parse(), Ok(Reducer::Sum));
+        assert!("average".parse::<Reducer>().is_err());
+    }
+
+    #[test]
+    fn standard_rules_match_puzzle() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+
+        assert_eq!(PartRule::standard().total(&schematic), Ok(4361));
+
+        assert_eq!(GearRule::standard().total(&schematic), Ok(467835));
+    }
+
+    #[test]
+    fn part_rule_limits_symbols() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+        let rule = PartRule {
+            symbols: SymbolSet::Only(vec!['#', '+']),
+        };
+
+        let values = rule
+            .part_numbers(&schematic)
+            .iter()
+            .map(|n| n.value)
+            .collect::<Vec<u32>>();
+        assert_eq!(values, vec![633, 592]);
+    }
+
+    #[test]
+    fn breakdown_works() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+        let breakdown = GearRule::standard().breakdown(&schematic).unwrap();
+
+        let values = breakdown
+            .iter()
+            .map(|b| (b.symbol.pos, b.numbers.len(), b.value))
+            .collect::<Vec<((usize, usize), usize, Option<u64>)>>();
+
+        assert_eq!(
+            values,
+            vec![
+                ((1, 3), 2, Some(16345)),
+                ((4, 3), 1, None),
+                ((8, 5), 2, Some(451490)),
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_gear_rule() {
+        let schematic = Schematic::parse(EXAMPLE).unwrap();
+        let rule = GearRule {
+            symbols: SymbolSet::Any,
+            neighbours: NeighbourCount::AtLeast(1),
+            reducer: Reducer::Sum,
+        };
+
+        // Every number next to a symbol is next to exactly one symbol here
+        assert_eq!(rule.total(&schematic), Ok(4361));
+
+        let rule = GearRule {
+            reducer: Reducer::Max,
+            ..rule
+        };
+        assert_eq!(rule.total(&schematic), Ok(4361 - 35 - 598));
+    }
+
+    #[test]
+    fn values_that_are_too_large() {
+        let big = "4294967295.4294967295\n..........*..........\n4294967295.4294967295";
+        let schematic = Schematic::parse(big).unwrap();
+
+        let product = GearRule {
+            neighbours: NeighbourCount::AtLeast(2),
+            ..GearRule::standard()
+        };
+        assert_eq!(
+            product.total(&schematic),
+            Err(GearError::ValueTooLarge { row: 1, col: 10 })
+        );
+
+        let sum = GearRule {
+            reducer: Reducer::Sum,
+            ..product
+        };
+        assert_eq!(sum.total(&schematic), Ok(4 * 4294967295));
+
+        // Too much for a u32, but not a u64
+        let schematic = Schematic::parse("4294967295*4294967295").unwrap();
+        assert_eq!(PartRule::standard().total(&schematic), Ok(2 * 4294967295));
+
+        // Each gear fits, but not both
+        let two = "4294967295*4294967295\n.....................\n4294967295*4294967295";
+        let schematic = Schematic::parse(two).unwrap();
+        assert_eq!(
+            GearRule::standard().total(&schematic),
+            Err(GearError::TotalTooLarge)
+        );
+
+        assert_eq!(
+            Reducer::Sum.reduce(&[u32::MAX; 4]),
+            Some(4 * u32::MAX as u64)
+        );
+        assert_eq!(
+            Reducer::Product.reduce(&[u32::MAX; 2]),
+            Some(u32::MAX as u64 * u32::MAX as u64)
+        );
+        assert_eq!(Reducer::Product.reduce(&[u32::MAX; 3]), None);
+    }
+}
@@ -0,0 +1,145 @@
+use day_04::{card_report, parse_cards, CardReport};
+
+// Prints a row per card showing how its copies cascade through the table.
+//
+// Usage: cargo run --bin report -- [--csv] [input file]
+
+fn main() {
+    let mut csv = false;
+    let mut path: Option<String> = None;
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--csv" => csv = true,
+            _ => path = Some(arg),
+        }
+    }
+
+    let input = match path {
+        Some(path) => std::fs::read_to_string(&path).expect("Should be able to read input file"),
+        None => include_str!("./input.txt").to_string(),
+    };
+
+    let report = match parse_cards(&input).and_then(|cards| card_report(&cards)) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    if csv {
+        print!("{}", to_csv(&report));
+    } else {
+        print!("{}", to_table(&report));
+    }
+}
+
+const HEADERS: [&str; 6] = ["card", "matches", "points", "copies", "won", "downstream"];
+
+fn columns(row: &CardReport) -> [String; 6] {
+    [
+        row.id.to_string(),
+        row.matches.to_string(),
+        row.points.to_string(),
+        row.copies.to_string(),
+        row.won.to_string(),
+        row.downstream.to_string(),
+    ]
+}
+
+fn to_csv(report: &[CardReport]) -> String {
+    let mut output = HEADERS.join(",") + "\n";
+
+    for row in report {
+        output += &columns(row).join(",");
+        output += "\n";
+    }
+
+    output
+}
+
+fn to_table(report: &[CardReport]) -> String {
+    let rows = report.iter().map(columns).collect::<Vec<[String; 6]>>();
+
+    let widths = (0..HEADERS.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain([HEADERS[i].len()])
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<usize>>();
+
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ")
+    };
+
+    let mut output = format_row(HEADERS.to_vec()) + "\n";
+
+    for row in &rows {
+        output += &format_row(row.iter().map(|cell| cell.as_str()).collect());
+        output += "\n";
+    }
+
+    if let Some(top) = report.iter().max_by_key(|row| row.downstream) {
+        output += &format!(
+            "\nCard {} produced the most copies downstream ({})\n",
+            top.id, top.downstream
+        );
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn csv_works() {
+        let report = card_report(&parse_cards(EXAMPLE).unwrap()).unwrap();
+
+        assert_eq!(
+            to_csv(&report),
+            "card,matches,points,copies,won,downstream
+1,4,8,1,4,14
+2,2,2,2,4,12
+3,2,2,4,8,12
+4,1,1,8,8,8
+5,0,0,14,0,0
+6,0,0,1,0,0
+"
+        );
+    }
+
+    #[test]
+    fn table_works() {
+        let report = card_report(&parse_cards(EXAMPLE).unwrap()).unwrap();
+        let table = to_table(&report);
+        let mut lines = table.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("card  matches  points  copies  won  downstream")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("   1        4       8       1    4          14")
+        );
+        assert!(table.ends_with("Card 1 produced the most copies downstream (14)\n"));
+    }
+}
@@ -0,0 +1,446 @@
+pub mod part1;
+pub mod part2;
+
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scratchcard {
+    pub id: usize,
+    pub winning: Vec<u32>,
+    pub have: Vec<u32>,
+}
+
+impl Scratchcard {
+    pub fn matching_numbers(&self) -> usize {
+        self.have
+            .iter()
+            .filter(|n| self.winning.contains(n))
+            .count()
+    }
+
+    // The first match is worth one point, every match after that doubles it
+    pub fn points(&self) -> u32 {
+        match self.matching_numbers() {
+            0 => 0,
+            m => 2u32.pow(m as u32 - 1),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CardError {
+    MissingLabel { line: usize },
+    BadLabel { line: usize, label: String },
+    MissingSeparator { line: usize },
+    BadNumber { line: usize, value: String },
+    DuplicateNumber { id: usize, number: u32 },
+    OutOfOrder { expected: usize, found: usize },
+    WinsPastEnd { ids: Vec<usize>, last_id: usize },
+    // More copies of the card than fit in a u64
+    TooManyCopies { id: usize },
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardError::MissingLabel { line } => {
+                write!(f, "Line {}: expected a `Card N:` label", line)
+            }
+            CardError::BadLabel { line, label } => {
+                write!(f, "Line {}: invalid card label {:?}", line, label)
+            }
+            CardError::MissingSeparator { line } => {
+                write!(f, "Line {}: expected a | between the number lists", line)
+            }
+            CardError::BadNumber { line, value } => {
+                write!(f, "Line {}: invalid number {:?}", line, value)
+            }
+            CardError::DuplicateNumber { id, number } => {
+                write!(f, "Card {}: number {} appears more than once", id, number)
+            }
+            CardError::OutOfOrder { expected, found } => {
+                write!(f, "Expected card {} but found card {}", expected, found)
+            }
+            CardError::WinsPastEnd { ids, last_id } => {
+                let ids = ids
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "Cards {} win copies of cards after the last card ({})",
+                    ids, last_id
+                )
+            }
+            CardError::TooManyCopies { id } => {
+                write!(f, "Card {}: too many copies to count", id)
+            }
+        }
+    }
+}
+
+pub fn parse_cards(input: &str) -> Result<Vec<Scratchcard>, CardError> {
+    let mut cards: Vec<Scratchcard> = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let card = parse_card(index + 1, line)?;
+
+        // Copies are won by card number, so the table has to be in order
+        if let Some(previous) = cards.last() {
+            if card.id != previous.id + 1 {
+                return Err(CardError::OutOfOrder {
+                    expected: previous.id + 1,
+                    found: card.id,
+                });
+            }
+        }
+
+        cards.push(card);
+    }
+
+    Ok(cards)
+}
+
+pub fn parse_card(line_number: usize, line: &str) -> Result<Scratchcard, CardError> {
+    let (label, numbers) = line
+        .split_once(":")
+        .ok_or(CardError::MissingLabel { line: line_number })?;
+
+    let id = label
+        .trim()
+        .strip_prefix("Card")
+        .and_then(|id| id.trim().parse::<usize>().ok())
+        .ok_or_else(|| CardError::BadLabel {
+            line: line_number,
+            label: label.trim().to_string(),
+        })?;
+
+    let (winning, have) = numbers
+        .split_once("|")
+        .ok_or(CardError::MissingSeparator { line: line_number })?;
+
+    let winning = parse_numbers(line_number, id, winning)?;
+    let have = parse_numbers(line_number, id, have)?;
+
+    Ok(Scratchcard { id, winning, have })
+}
+
+fn parse_numbers(line_number: usize, id: usize, numbers: &str) -> Result<Vec<u32>, CardError> {
+    let mut seen = HashSet::new();
+
+    numbers
+        .split_whitespace()
+        .map(|value| {
+            let number = value.parse::<u32>().map_err(|_| CardError::BadNumber {
+                line: line_number,
+                value: value.to_string(),
+            })?;
+
+            if !seen.insert(number) {
+                return Err(CardError::DuplicateNumber { id, number });
+            }
+
+            Ok(number)
+        })
+        .collect()
+}
+
+// Cards only ever win copies of cards after them, so by the time we reach
+// card N we already know how many copies of it we hold. Each of those copies
+// wins one copy of cards N+1..N+M, so we can carry the counts forward in a
+// single pass instead of simulating every copy. The counts can still grow
+// exponentially down the table, so they're checked.
+pub fn copies_held(cards: &[Scratchcard]) -> Result<Vec<u64>, CardError> {
+    let past_end = cards
+        .iter()
+        .enumerate()
+        .filter(|(ndx, card)| ndx + card.matching_numbers() >= cards.len())
+        .map(|(_, card)| card.id)
+        .collect::<Vec<usize>>();
+
+    if !past_end.is_empty() {
+        return Err(CardError::WinsPastEnd {
+            ids: past_end,
+            last_id: cards.last().map(|card| card.id).unwrap_or(0),
+        });
+    }
+
+    let mut copies = vec![1u64; cards.len()];
+
+    for (ndx, card) in cards.iter().enumerate() {
+        for next_ndx in ndx + 1..=ndx + card.matching_numbers() {
+            copies[next_ndx] =
+                copies[next_ndx]
+                    .checked_add(copies[ndx])
+                    .ok_or(CardError::TooManyCopies {
+                        id: cards[next_ndx].id,
+                    })?;
+        }
+    }
+
+    Ok(copies)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CardReport {
+    pub id: usize,
+    pub matches: usize,
+    pub points: u32,
+    pub copies: u64,
+    // Copies won directly by every copy of this card
+    pub won: u64,
+    // Copies won by this card plus everything those copies go on to win
+    pub downstream: u64,
+}
+
+pub fn card_report(cards: &[Scratchcard]) -> Result<Vec<CardReport>, CardError> {
+    let copies = copies_held(cards)?;
+
+    // Work backwards so the cards a copy wins already know their cascade
+    let mut cascade = vec![0u64; cards.len()];
+
+    for (ndx, card) in cards.iter().enumerate().rev() {
+        cascade[ndx] = (ndx + 1..=ndx + card.matching_numbers())
+            .try_fold(0u64, |total, next_ndx| {
+                total.checked_add(cascade[next_ndx].checked_add(1)?)
+            })
+            .ok_or(CardError::TooManyCopies { id: card.id })?;
+    }
+
+    cards
+        .iter()
+        .enumerate()
+        .map(|(ndx, card)| {
+            let too_many = || CardError::TooManyCopies { id: card.id };
+
+            Ok(CardReport {
+                id: card.id,
+                matches: card.matching_numbers(),
+                points: card.points(),
+                copies: copies[ndx],
+                won: copies[ndx]
+                    .checked_mul(card.matching_numbers() as u64)
+                    .ok_or_else(too_many)?,
+                downstream: copies[ndx].checked_mul(cascade[ndx]).ok_or_else(too_many)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rng::Rng;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    // Builds a card with exactly `matches` matching numbers
+    fn card_with_matches(id: usize, matches: usize) -> Scratchcard {
+        let winning = (1..=5).collect::<Vec<u32>>();
+        let have = (1..=matches as u32)
+            .chain(50..58 - matches as u32)
+            .collect();
+
+        Scratchcard { id, winning, have }
+    }
+
+    // We'll want a stack of collected cards. This starts empty.
+    // We also have a stack of cards. These are processed.
+    // When a card with index N is processed we:
+    // 1. Add card N to the stack of collected cards
+    // 2. Add copies of cards N+1..N+M to the stack of cards, where M
+    // is the number of winning hands in card N (iff M is > 0)
+    //
+    // This is exponential in the worst case, but it is easy to trust so
+    // we keep it around to check copies_held against.
+    fn simulate_cards(cards: &[Scratchcard]) -> u64 {
+        let cards = cards
+            .iter()
+            .map(|card| (card.id, card.matching_numbers()))
+            .collect::<Vec<(usize, usize)>>();
+
+        let mut collected_cards: Vec<(usize, usize)> = Vec::new();
+
+        // Processing order doesn't matter so lets just use Vec
+        let mut cards_to_process = cards.clone();
+
+        while let Some(card) = cards_to_process.pop() {
+            collected_cards.push(card);
+
+            let winning_numbers = card.1;
+            if winning_numbers > 0 {
+                let next_card_ndx = card.0;
+                let last_card_ndx = card.0 + winning_numbers;
+
+                cards_to_process.extend_from_slice(&cards[next_card_ndx..last_card_ndx]);
+            }
+        }
+
+        collected_cards.len() as u64
+    }
+
+    #[test]
+    fn parse_card_handles_spacing() {
+        let result = parse_card(1, "Card   3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1");
+        assert_eq!(
+            result,
+            Ok(Scratchcard {
+                id: 3,
+                winning: vec![1, 21, 53, 59, 44],
+                have: vec![69, 82, 63, 72, 16, 21, 14, 1],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_card_rejects_bad_lines() {
+        assert_eq!(
+            parse_card(1, "Card 1 41 48 | 83 86"),
+            Err(CardError::MissingLabel { line: 1 })
+        );
+        assert_eq!(
+            parse_card(2, "Cart 1: 41 48 | 83 86"),
+            Err(CardError::BadLabel {
+                line: 2,
+                label: "Cart 1".to_string()
+            })
+        );
+        assert_eq!(
+            parse_card(3, "Card 1: 41 48 83 86"),
+            Err(CardError::MissingSeparator { line: 3 })
+        );
+        assert_eq!(
+            parse_card(4, "Card 1: 41 4x | 83 86"),
+            Err(CardError::BadNumber {
+                line: 4,
+                value: "4x".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parse_card_flags_duplicates() {
+        let result = parse_card(1, "Card 7: 41 48 41 | 83 86");
+        assert_eq!(
+            result,
+            Err(CardError::DuplicateNumber { id: 7, number: 41 })
+        );
+    }
+
+    #[test]
+    fn parse_cards_uses_card_label() {
+        let cards = parse_cards("Card 5: 1 | 2\nCard 6: 3 | 4").unwrap();
+        assert_eq!(cards[0].id, 5);
+        assert_eq!(cards[1].id, 6);
+
+        let result = parse_cards("Card 1: 1 | 2\nCard 3: 3 | 4");
+        assert_eq!(
+            result,
+            Err(CardError::OutOfOrder {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn points_works() {
+        let cards = parse_cards(EXAMPLE).unwrap();
+        let points = cards.iter().map(|card| card.points()).collect::<Vec<u32>>();
+        assert_eq!(points, vec![8, 2, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn copies_held_works() {
+        let cards = parse_cards(EXAMPLE).unwrap();
+        assert_eq!(copies_held(&cards), Ok(vec![1, 2, 4, 8, 14, 1]));
+    }
+
+    #[test]
+    fn card_report_works() {
+        let cards = parse_cards(EXAMPLE).unwrap();
+        let report = card_report(&cards).unwrap();
+
+        let won = report.iter().map(|r| r.won).collect::<Vec<u64>>();
+        assert_eq!(won, vec![4, 4, 8, 8, 0, 0]);
+
+        // A copy of card 4 wins a 5, a copy of card 3 wins a 4 and a 5 and
+        // that 4 wins another 5, and so on
+        let downstream = report.iter().map(|r| r.downstream).collect::<Vec<u64>>();
+        assert_eq!(downstream, vec![14, 12, 12, 8, 0, 0]);
+
+        // Everything except the originals was won by someone
+        let total_won = report.iter().map(|r| r.won).sum::<u64>();
+        assert_eq!(total_won, 30 - 6);
+    }
+
+    #[test]
+    fn copies_held_reports_wins_past_end() {
+        let cards = parse_cards("Card 1: 1 | 1\nCard 2: 3 4 | 3 4\nCard 3: 5 | 5").unwrap();
+        assert_eq!(
+            copies_held(&cards),
+            Err(CardError::WinsPastEnd {
+                ids: vec![2, 3],
+                last_id: 3
+            })
+        );
+    }
+
+    #[test]
+    fn copies_held_reports_too_many_copies() {
+        // Every card wins the next two, so the copies grow like Fibonacci
+        // numbers
+        let chain = |count: usize| {
+            (0..count)
+                .map(|ndx| card_with_matches(ndx + 1, 2.min(count - ndx - 1)))
+                .collect::<Vec<Scratchcard>>()
+        };
+
+        let copies = copies_held(&chain(80)).unwrap();
+        assert_eq!(copies[..5], [1, 2, 4, 7, 12]);
+        assert_eq!(
+            copies_held(&chain(100)),
+            Err(CardError::TooManyCopies { id: 92 })
+        );
+
+        // The copies still fit, but what the copies of card 3 go on to win
+        // doesn't
+        assert!(copies_held(&chain(91)).is_ok());
+        assert_eq!(
+            card_report(&chain(91)),
+            Err(CardError::TooManyCopies { id: 3 })
+        );
+        assert!(card_report(&chain(80)).is_ok());
+    }
+
+    #[test]
+    fn copies_held_matches_simulation() {
+        let mut rng = Rng::new(2023);
+
+        for _ in 0..50 {
+            let card_count = 1 + rng.next(15) as usize;
+
+            // Winnings never reach past the end of the table
+            let cards = (0..card_count)
+                .map(|ndx| {
+                    let remaining = (card_count - ndx - 1) as u64;
+                    card_with_matches(ndx + 1, rng.next(remaining.min(5) + 1) as usize)
+                })
+                .collect::<Vec<Scratchcard>>();
+
+            let total = copies_held(&cards).unwrap().iter().sum::<u64>();
+            assert_eq!(total, simulate_cards(&cards), "{:?}", cards);
+        }
+    }
+}
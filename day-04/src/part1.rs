@@ -0,0 +1,9 @@
+use crate::{parse_cards, CardError, Scratchcard};
+
+pub fn parse(input: &str) -> Result<Vec<Scratchcard>, CardError> {
+    parse_cards(input)
+}
+
+pub fn solve(cards: &[Scratchcard]) -> u32 {
+    cards.iter().map(|card| card.points()).sum()
+}
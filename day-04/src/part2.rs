@@ -0,0 +1,18 @@
+use crate::{copies_held, parse_cards, CardError, Scratchcard};
+
+pub fn parse(input: &str) -> Result<Vec<Scratchcard>, CardError> {
+    parse_cards(input)
+}
+
+pub fn solve(cards: &[Scratchcard]) -> Result<u64, CardError> {
+    let copies = copies_held(cards)?;
+
+    cards
+        .iter()
+        .zip(copies)
+        .try_fold(0u64, |total, (card, copies)| {
+            total
+                .checked_add(copies)
+                .ok_or(CardError::TooManyCopies { id: card.id })
+        })
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Node<'a> {
+    id: &'a str,
+    left: &'a str,
+    right: &'a str,
+}
+
+impl<'a> Node<'a> {
+    fn step(&self, direction: char) -> &'a str {
+        match direction {
+            'L' => self.left,
+            'R' => self.right,
+            _ => panic!("Invalid direction: {}", direction),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum WalkError<'a> {
+    NoDirections,
+    MissingNode(&'a str),
+    Unreachable {
+        start: &'a str,
+        goal: &'a str,
+        cycle: Vec<(&'a str, usize)>,
+    },
+}
+
+impl fmt::Display for WalkError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WalkError::NoDirections => write!(f, "No directions given"),
+            WalkError::MissingNode(id) => write!(f, "Node {} is not defined", id),
+            WalkError::Unreachable { start, goal, cycle } => {
+                let cycle = cycle
+                    .iter()
+                    .map(|(id, i)| format!("{}[{}]", id, i))
+                    .collect::<Vec<String>>()
+                    .join(" -> ");
+                write!(
+                    f,
+                    "{} can never be reached from {}, the walk gets stuck in the cycle {}",
+                    goal, start, cycle
+                )
+            }
+        }
+    }
+}
+
+// The directions and every node, keyed by id
+#[derive(Debug)]
+pub struct Network<'a> {
+    directions: &'a str,
+    nodes: HashMap<&'a str, Node<'a>>,
+}
+
+pub fn parse(input: &str) -> Network<'_> {
+    let mut lines = input.lines();
+
+    let directions = lines.next().expect("First line should be directions");
+
+    lines.next(); // Skip blank line
+
+    let mut nodes = HashMap::new();
+
+    for line in lines {
+        let node = parse_node(line);
+        nodes.insert(node.id, node);
+    }
+
+    Network { directions, nodes }
+}
+
+pub fn solve<'a>(network: &Network<'a>) -> Result<u32, WalkError<'a>> {
+    let Network { directions, nodes } = network;
+
+    check_reachable(nodes, directions, "AAA", "ZZZ")?;
+
+    let mut current_node = "AAA";
+    let mut steps = 0;
+
+    loop {
+        for direction in directions.chars() {
+            steps += 1;
+
+            let node = nodes.get(current_node).expect("Invalid node");
+            current_node = node.step(direction);
+
+            if current_node == "ZZZ" {
+                return Ok(steps);
+            }
+        }
+    }
+}
+
+// The walk is fully determined by the current node and where we are in the
+// directions, so there are only nodes * directions states. If we see the same
+// state twice before hitting the goal we are going around in circles forever.
+fn check_reachable<'a>(
+    nodes: &HashMap<&'a str, Node<'a>>,
+    directions: &str,
+    start: &'a str,
+    goal: &'a str,
+) -> Result<(), WalkError<'a>> {
+    let directions = directions.chars().collect::<Vec<char>>();
+
+    if directions.is_empty() {
+        return Err(WalkError::NoDirections);
+    }
+
+    let mut seen: HashMap<(&str, usize), usize> = HashMap::new();
+    let mut path: Vec<(&str, usize)> = Vec::new();
+
+    let mut current_node = start;
+    let mut direction_index = 0;
+
+    loop {
+        let state = (current_node, direction_index);
+
+        if let Some(&cycle_start) = seen.get(&state) {
+            let mut cycle = path[cycle_start..].to_vec();
+            cycle.push(state);
+
+            return Err(WalkError::Unreachable { start, goal, cycle });
+        }
+
+        seen.insert(state, path.len());
+        path.push(state);
+
+        let node = nodes
+            .get(current_node)
+            .ok_or(WalkError::MissingNode(current_node))?;
+        current_node = node.step(directions[direction_index]);
+
+        if current_node == goal {
+            return Ok(());
+        }
+
+        direction_index = (direction_index + 1) % directions.len();
+    }
+}
+
+fn parse_node(line: &str) -> Node<'_> {
+    let id = &line[0..3];
+    let left = &line[7..10];
+    let right = &line[12..15];
+
+    Node { id, left, right }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_node_works() {
+        let input = "AAA = (BBB, CCC)";
+        let result = parse_node(input);
+        assert_eq!(result.left, "BBB");
+        assert_eq!(result.right, "CCC");
+    }
+
+    #[test]
+    fn unreachable_goal_reports_cycle() {
+        let input = "LR
+
+AAA = (BBB, CCC)
+BBB = (AAA, AAA)
+CCC = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let result = solve(&parse(input));
+        assert_eq!(
+            result,
+            Err(WalkError::Unreachable {
+                start: "AAA",
+                goal: "ZZZ",
+                cycle: vec![("AAA", 0), ("BBB", 1), ("AAA", 0)],
+            })
+        );
+    }
+
+    #[test]
+    fn missing_node_is_an_error() {
+        let input = "L
+
+AAA = (BBB, BBB)";
+        let result = solve(&parse(input));
+        assert_eq!(result, Err(WalkError::MissingNode("BBB")));
+    }
+}
@@ -0,0 +1,31 @@
+use day_09::{extrapolate_and_fit, Direction};
+
+// Prints the polynomial behind each history, with x = 0 as the first value,
+// and the next value it gives.
+//
+// Usage: cargo run --bin polynomials -- [input file]
+
+fn main() {
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path).expect("Should be able to read input file"),
+        None => include_str!("./input.txt").to_string(),
+    };
+
+    for (index, line) in input.lines().enumerate() {
+        let values = line
+            .split_whitespace()
+            .map(|s| s.parse().expect("Line should include numbers"))
+            .collect::<Vec<i64>>();
+
+        match extrapolate_and_fit(&values, Direction::Forward, 1) {
+            Ok(fit) => println!(
+                "{}: f(x) = {} (degree {}), next {}",
+                index + 1,
+                fit.polynomial,
+                fit.extrapolation.degree,
+                fit.extrapolation.value
+            ),
+            Err(err) => println!("{}: {}", index + 1, err),
+        }
+    }
+}
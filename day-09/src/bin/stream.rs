@@ -0,0 +1,77 @@
+use std::io::{self, BufReader};
+
+use day_09::stream::read_histories;
+use day_09::Direction;
+
+// Same answers as part 1 and part 2, but reads the histories a value at a
+// time so they can be as long as you like.
+//
+// Usage: cargo run --release --bin stream -- [input file]
+//        some-generator | cargo run --release --bin stream
+
+fn main() {
+    let result = match std::env::args().nth(1) {
+        Some(path) => {
+            let file = std::fs::File::open(path).expect("Should be able to open input file");
+            run(BufReader::new(file))
+        }
+        None => run(io::stdin().lock()),
+    };
+
+    match result {
+        Ok((next, previous)) => {
+            println!("Next values: {}", next);
+            println!("Previous values: {}", previous);
+        }
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn run<R: io::BufRead>(reader: R) -> Result<(i128, i128), String> {
+    let mut next = 0i128;
+    let mut previous = 0i128;
+
+    for (index, history) in read_histories(reader).enumerate() {
+        let history = history.map_err(|err| format!("History {}: {}", index + 1, err))?;
+
+        let forward = history
+            .predict(Direction::Forward, 1)
+            .map_err(|err| format!("History {}: {}", index + 1, err))?;
+        let backward = history
+            .predict(Direction::Backward, 1)
+            .map_err(|err| format!("History {}: {}", index + 1, err))?;
+
+        next = next
+            .checked_add(forward.value)
+            .ok_or("Sum of next values overflowed")?;
+        previous = previous
+            .checked_add(backward.value)
+            .ok_or("Sum of previous values overflowed")?;
+    }
+
+    Ok((next, previous))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let result = run(input.as_bytes());
+        assert_eq!(result, Ok((114, 2)));
+    }
+
+    #[test]
+    fn matches_puzzle_answers() {
+        let input = include_str!("./input.txt");
+        let result = run(input.as_bytes());
+        assert_eq!(result, Ok((2043677056, 1062)));
+    }
+}
@@ -0,0 +1,345 @@
+pub mod part1;
+pub mod part2;
+pub mod polynomial;
+pub mod stream;
+
+use std::fmt;
+
+use polynomial::Polynomial;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExtrapolationError {
+    Empty,
+    // Every row of the difference pyramid has something non zero in it, so
+    // there is nothing to confirm the pattern carries on
+    NotPolynomial { length: usize },
+    Overflow,
+}
+
+impl fmt::Display for ExtrapolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtrapolationError::Empty => write!(f, "Sequence has no values"),
+            ExtrapolationError::NotPolynomial { length } => write!(
+                f,
+                "Sequence of {} values never reaches a row of zero differences",
+                length
+            ),
+            ExtrapolationError::Overflow => write!(f, "Value is too large to represent"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extrapolation {
+    pub value: i128,
+    pub degree: usize,
+}
+
+// Predicts the value `steps` places after the last value (Forward) or before
+// the first value (Backward).
+pub fn extrapolate(
+    values: &[i64],
+    direction: Direction,
+    steps: u64,
+) -> Result<Extrapolation, ExtrapolationError> {
+    Ok(Extrapolation {
+        value: value_at(values, position(values, direction, steps)?)?,
+        degree: degree(values)?,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fit {
+    pub extrapolation: Extrapolation,
+    pub polynomial: Polynomial,
+}
+
+// The same as extrapolate, but also hands back the polynomial the value came
+// from, built from the same leading differences, so it can be checked or
+// evaluated anywhere else.
+pub fn extrapolate_and_fit(
+    values: &[i64],
+    direction: Direction,
+    steps: u64,
+) -> Result<Fit, ExtrapolationError> {
+    let x = position(values, direction, steps)?;
+    let degree = degree(values)?;
+    let differences = leading_differences(values).ok_or(ExtrapolationError::Overflow)?;
+
+    Ok(Fit {
+        extrapolation: Extrapolation {
+            value: newton(&differences, x).ok_or(ExtrapolationError::Overflow)?,
+            degree,
+        },
+        polynomial: polynomial::from_differences(&differences[..=degree])?,
+    })
+}
+
+// Where the value we're after is, with x = 0 as the first value
+fn position(values: &[i64], direction: Direction, steps: u64) -> Result<i128, ExtrapolationError> {
+    let steps = i128::from(steps);
+
+    match direction {
+        Direction::Forward => (values.len() as i128 - 1).checked_add(steps),
+        Direction::Backward => steps.checked_neg(),
+    }
+    .ok_or(ExtrapolationError::Overflow)
+}
+
+// Newton's formula below writes the polynomial as a sum of C(x, j) terms, and
+// C(x, j) has degree j, so the degree is the last non zero leading difference.
+// With n values that can be at most n - 1, but then the bottom row of the
+// pyramid is a single non zero number and we can't tell if it really is a
+// polynomial, so we need at least one row of zeros below it.
+pub fn degree(values: &[i64]) -> Result<usize, ExtrapolationError> {
+    if values.is_empty() {
+        return Err(ExtrapolationError::Empty);
+    }
+
+    let degree = leading_differences(values)
+        .ok_or(ExtrapolationError::Overflow)?
+        .iter()
+        .rposition(|difference| *difference != 0)
+        .unwrap_or(0);
+
+    if values.iter().any(|&x| x != 0) && degree + 1 >= values.len() {
+        return Err(ExtrapolationError::NotPolynomial {
+            length: values.len(),
+        });
+    }
+
+    Ok(degree)
+}
+
+// Treats the values as f(0), f(1), .. f(n - 1) and evaluates the polynomial
+// through them at any x using Newton's forward difference formula:
+//
+//   f(x) = sum over j of  Δʲf(0) * C(x, j)
+//
+// This gives the same answer as building the difference pyramid and adding
+// up the ends of each row, but only needs the leading diagonal.
+pub fn value_at(values: &[i64], x: i128) -> Result<i128, ExtrapolationError> {
+    leading_differences(values)
+        .and_then(|differences| newton(&differences, x))
+        .ok_or(ExtrapolationError::Overflow)
+}
+
+fn newton(differences: &[i128], x: i128) -> Option<i128> {
+    differences
+        .iter()
+        .enumerate()
+        .filter(|(_, difference)| **difference != 0)
+        .try_fold(0i128, |total, (j, difference)| {
+            let term = difference.checked_mul(binomial(x, j as u64)?)?;
+            total.checked_add(term)
+        })
+}
+
+// The first value of every row of the difference pyramid, worked out directly
+// from the values using
+//
+//   Δʲf(0) = sum over i of  (-1)^(j - i) * C(j, i) * f(i)
+pub fn leading_differences(values: &[i64]) -> Option<Vec<i128>> {
+    (0..values.len())
+        .map(|j| {
+            values[..=j]
+                .iter()
+                .enumerate()
+                .try_fold(0i128, |total, (i, value)| {
+                    let term = binomial(j as i128, i as u64)?.checked_mul(i128::from(*value))?;
+
+                    if (j - i) % 2 == 0 {
+                        total.checked_add(term)
+                    } else {
+                        total.checked_sub(term)
+                    }
+                })
+        })
+        .collect()
+}
+
+// C(x, k) = x (x - 1) .. (x - k + 1) / k!, which also makes sense for
+// negative x. After each step we have C(x, t + 1), and the product of t + 1
+// consecutive integers is always divisible by (t + 1)!, so the division is exact.
+pub fn binomial(x: i128, k: u64) -> Option<i128> {
+    (0..k).try_fold(1i128, |c, t| {
+        let t = i128::from(t);
+        Some(c.checked_mul(x.checked_sub(t)?)? / (t + 1))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::Rational;
+
+    #[test]
+    fn binomial_works() {
+        assert_eq!(binomial(5, 0), Some(1));
+        assert_eq!(binomial(5, 2), Some(10));
+        assert_eq!(binomial(5, 6), Some(0));
+        assert_eq!(binomial(-1, 3), Some(-1));
+        assert_eq!(binomial(-3, 2), Some(6));
+    }
+
+    #[test]
+    fn leading_differences_works() {
+        //1   3   6  10  15  21
+        //   2   3   4   5   6
+        //     1   1   1   1
+        //       0   0   0
+        let result = leading_differences(&[1, 3, 6, 10, 15, 21]);
+        assert_eq!(result, Some(vec![1, 2, 1, 0, 0, 0]));
+    }
+
+    #[test]
+    fn extrapolate_one_step_matches_examples() {
+        assert_eq!(
+            extrapolate(&[0, 3, 6, 9, 12, 15], Direction::Forward, 1).map(|e| e.value),
+            Ok(18)
+        );
+        assert_eq!(
+            extrapolate(&[1, 3, 6, 10, 15, 21], Direction::Forward, 1).map(|e| e.value),
+            Ok(28)
+        );
+        assert_eq!(
+            extrapolate(&[10, 13, 16, 21, 30, 45], Direction::Forward, 1).map(|e| e.value),
+            Ok(68)
+        );
+
+        assert_eq!(
+            extrapolate(&[0, 3, 6, 9, 12, 15], Direction::Backward, 1).map(|e| e.value),
+            Ok(-3)
+        );
+        assert_eq!(
+            extrapolate(&[1, 3, 6, 10, 15, 21], Direction::Backward, 1).map(|e| e.value),
+            Ok(0)
+        );
+        assert_eq!(
+            extrapolate(&[10, 13, 16, 21, 30, 45], Direction::Backward, 1).map(|e| e.value),
+            Ok(5)
+        );
+    }
+
+    #[test]
+    fn extrapolate_many_steps() {
+        // Triangular numbers, f(x) = (x + 1)(x + 2) / 2
+        let values = [1, 3, 6, 10, 15, 21];
+        let f = |x: i128| (x + 1) * (x + 2) / 2;
+
+        assert_eq!(
+            extrapolate(&values, Direction::Forward, 3).map(|e| e.value),
+            Ok(f(8))
+        );
+        assert_eq!(
+            extrapolate(&values, Direction::Backward, 4).map(|e| e.value),
+            Ok(f(-4))
+        );
+        assert_eq!(
+            extrapolate(&values, Direction::Forward, 1_000_000_000_000).map(|e| e.value),
+            Ok(f(1_000_000_000_005))
+        );
+        assert_eq!(
+            extrapolate(&values, Direction::Backward, 1_000_000_000_000_000_000).map(|e| e.value),
+            Ok(f(-1_000_000_000_000_000_000))
+        );
+    }
+
+    #[test]
+    fn extrapolate_reports_overflow() {
+        // f(x) = x^5 overflows an i128 long before x = u64::MAX
+        let values = (0..8).map(|x: i64| x.pow(5)).collect::<Vec<i64>>();
+
+        assert_eq!(
+            extrapolate(&values, Direction::Forward, 1).map(|e| e.value),
+            Ok(8i128.pow(5))
+        );
+        assert_eq!(
+            extrapolate(&values, Direction::Forward, u64::MAX),
+            Err(ExtrapolationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn extrapolate_reports_degree() {
+        let result = extrapolate(&[10, 13, 16, 21, 30, 45], Direction::Forward, 1);
+        assert_eq!(
+            result,
+            Ok(Extrapolation {
+                value: 68,
+                degree: 3
+            })
+        );
+
+        let result = extrapolate(&[7, 7, 7], Direction::Backward, 2);
+        assert_eq!(
+            result,
+            Ok(Extrapolation {
+                value: 7,
+                degree: 0
+            })
+        );
+
+        let result = extrapolate(&[0, 0], Direction::Forward, 1);
+        assert_eq!(
+            result,
+            Ok(Extrapolation {
+                value: 0,
+                degree: 0
+            })
+        );
+    }
+
+    #[test]
+    fn extrapolate_and_fit_works() {
+        let values = [10, 13, 16, 21, 30, 45];
+
+        for direction in [Direction::Forward, Direction::Backward] {
+            for steps in [1, 2, 1_000_000] {
+                let fit = extrapolate_and_fit(&values, direction, steps).unwrap();
+                let x = position(&values, direction, steps).unwrap();
+
+                assert_eq!(
+                    Ok(fit.extrapolation),
+                    extrapolate(&values, direction, steps)
+                );
+                assert_eq!(
+                    fit.polynomial.evaluate(Rational::integer(x)),
+                    Some(Rational::integer(fit.extrapolation.value))
+                );
+            }
+        }
+
+        let fit = extrapolate_and_fit(&values, Direction::Forward, 1).unwrap();
+        assert_eq!(fit.extrapolation.value, 68);
+        assert_eq!(fit.polynomial.to_string(), "x^3/3 - x^2 + 11x/3 + 10");
+        assert_eq!(fit.polynomial.degree(), fit.extrapolation.degree);
+
+        assert_eq!(
+            extrapolate_and_fit(&[1, 2, 4, 8], Direction::Forward, 1),
+            Err(ExtrapolationError::NotPolynomial { length: 4 })
+        );
+    }
+
+    #[test]
+    fn degree_rejects_sequences_that_are_not_polynomial() {
+        assert_eq!(degree(&[]), Err(ExtrapolationError::Empty));
+        assert_eq!(
+            degree(&[5]),
+            Err(ExtrapolationError::NotPolynomial { length: 1 })
+        );
+        // Powers of two never settle down
+        assert_eq!(
+            degree(&[1, 2, 4, 8, 16, 32]),
+            Err(ExtrapolationError::NotPolynomial { length: 6 })
+        );
+        assert_eq!(degree(&[1, 2, 4, 7, 11, 16]), Ok(2));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::ExtrapolationError;
+
+pub fn parse(input: &str) -> Vec<Vec<i64>> {
+    input.lines().map(parse_line).collect()
+}
+
+pub fn solve(sequences: &[Vec<i64>]) -> Result<i64, ExtrapolationError> {
+    sequences.iter().try_fold(0i64, |sum, values| {
+        let values_with_differences = derive_difference_vectors(values.clone())?;
+        let prediction = predict_next_value(values_with_differences)?;
+
+        sum.checked_add(prediction)
+            .ok_or(ExtrapolationError::Overflow)
+    })
+}
+
+fn parse_line(input: &str) -> Vec<i64> {
+    input
+        .split_whitespace()
+        .map(|s| s.parse().expect("Line should include numbers"))
+        .collect::<Vec<i64>>()
+}
+
+fn derive_difference_vector(values: &[i64]) -> Result<Vec<i64>, ExtrapolationError> {
+    values
+        .windows(2)
+        .map(|window| {
+            window[1]
+                .checked_sub(window[0])
+                .ok_or(ExtrapolationError::Overflow)
+        })
+        .collect()
+}
+
+// Keep going until we hit a row of zeros. If we run out of values first the
+// sequence isn't a polynomial we can see the end of, and the last row would
+// just be whatever was left over, so that is an error.
+fn derive_difference_vectors(values: Vec<i64>) -> Result<Vec<Vec<i64>>, ExtrapolationError> {
+    if values.is_empty() {
+        return Err(ExtrapolationError::Empty);
+    }
+
+    let length = values.len();
+    let mut output = vec![values];
+
+    loop {
+        let last = output.last().expect("Should be a latest output");
+
+        if last.iter().all(|&x| x == 0) {
+            return Ok(output);
+        }
+
+        // If there are less than 2 values, we can't derive a difference vector
+        if last.len() < 2 {
+            return Err(ExtrapolationError::NotPolynomial { length });
+        }
+
+        let difference_vector = derive_difference_vector(last)?;
+        output.push(difference_vector);
+    }
+}
+
+fn predict_next_value(values_with_differences: Vec<Vec<i64>>) -> Result<i64, ExtrapolationError> {
+    values_with_differences
+        .iter()
+        .rev()
+        .map(|v| *v.last().expect("Should be a last value"))
+        .try_fold(0i64, |a, b| a.checked_add(b))
+        .ok_or(ExtrapolationError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derive_difference_vector_works() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+        let result = derive_difference_vector(&input);
+        assert_eq!(result, Ok(vec![3, 3, 3, 3, 3]));
+    }
+
+    #[test]
+    fn derive_difference_vectors_works() {
+        // 0   3   6   9  12  15
+        //   3   3   3   3   3
+        //     0   0   0   0
+        let input = vec![0, 3, 6, 9, 12, 15];
+        let result = derive_difference_vectors(input);
+        assert_eq!(
+            result,
+            Ok(vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0],
+            ])
+        );
+
+        //1   3   6  10  15  21
+        //   2   3   4   5   6
+        //     1   1   1   1
+        //       0   0   0
+        let input = vec![1, 3, 6, 10, 15, 21];
+        let result = derive_difference_vectors(input);
+        assert_eq!(
+            result,
+            Ok(vec![
+                vec![1, 3, 6, 10, 15, 21],
+                vec![2, 3, 4, 5, 6],
+                vec![1, 1, 1, 1],
+                vec![0, 0, 0],
+            ])
+        );
+    }
+
+    #[test]
+    fn predict_next_value_works() {
+        // 1   3   6  10  15  21  (28)
+        //   2   3   4   5   6  (7)
+        //   1   1   1   1   (1)
+        //     0   0   0   (0)
+        let input = vec![
+            vec![1, 3, 6, 10, 15, 21],
+            vec![2, 3, 4, 5, 6],
+            vec![1, 1, 1, 1],
+            vec![0, 0, 0],
+        ];
+        let result = predict_next_value(input);
+        assert_eq!(result, Ok(28));
+    }
+
+    #[test]
+    fn parse_line_works() {
+        let input = "0 3 6 9 12 15";
+        let result = parse_line(input);
+        assert_eq!(result, vec![0, 3, 6, 9, 12, 15]);
+    }
+
+    #[test]
+    fn derive_difference_vectors_rejects_non_polynomials() {
+        // 1   2   4   8
+        //   1   2   4
+        //     1   2
+        //       1
+        let result = derive_difference_vectors(vec![1, 2, 4, 8]);
+        assert_eq!(result, Err(ExtrapolationError::NotPolynomial { length: 4 }));
+
+        let result = derive_difference_vectors(vec![]);
+        assert_eq!(result, Err(ExtrapolationError::Empty));
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let result = derive_difference_vectors(vec![i64::MIN, i64::MAX, 0]);
+        assert_eq!(result, Err(ExtrapolationError::Overflow));
+
+        let input = format!("{} {} {}", i64::MAX - 2, i64::MAX - 1, i64::MAX);
+        let result = solve(&parse(&input));
+        assert_eq!(result, Err(ExtrapolationError::Overflow));
+    }
+}
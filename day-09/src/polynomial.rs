@@ -0,0 +1,300 @@
+use std::fmt;
+
+use crate::{degree, leading_differences, ExtrapolationError};
+
+// -------- RATIONALS ---------
+
+// Always stored in lowest terms with a positive denominator, so derived
+// equality works
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rational {
+    pub numerator: i128,
+    pub denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Option<Rational> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator);
+        let sign = if denominator < 0 { -1 } else { 1 };
+
+        Some(Rational {
+            numerator: (numerator / divisor).checked_mul(sign)?,
+            denominator: (denominator / divisor).checked_mul(sign)?,
+        })
+    }
+
+    pub fn integer(value: i128) -> Rational {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn checked_add(&self, other: Rational) -> Option<Rational> {
+        let divisor = gcd(self.denominator, other.denominator);
+        let denominator = (self.denominator / divisor).checked_mul(other.denominator)?;
+
+        let numerator = self
+            .numerator
+            .checked_mul(denominator / self.denominator)?
+            .checked_add(
+                other
+                    .numerator
+                    .checked_mul(denominator / other.denominator)?,
+            )?;
+
+        Rational::new(numerator, denominator)
+    }
+
+    pub fn checked_mul(&self, other: Rational) -> Option<Rational> {
+        // Cross cancel first to keep the intermediate values small
+        let a = gcd(self.numerator, other.denominator);
+        let b = gcd(other.numerator, self.denominator);
+
+        Rational::new(
+            (self.numerator / a).checked_mul(other.numerator / b)?,
+            (self.denominator / b).checked_mul(other.denominator / a)?,
+        )
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    // gcd(0, 0) would be 0, dividing by 1 leaves things alone instead
+    a.max(1) as i128
+}
+
+// -------- POLYNOMIALS ---------
+
+// coefficients[k] is the coefficient of x^k, where x = 0 is the first value
+// of the sequence
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    pub coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    pub fn degree(&self) -> usize {
+        self.coefficients
+            .iter()
+            .rposition(|c| !c.is_zero())
+            .unwrap_or(0)
+    }
+
+    pub fn evaluate(&self, x: Rational) -> Option<Rational> {
+        // Horner's method
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(Rational::integer(0), |total, c| {
+                total.checked_mul(x)?.checked_add(*c)
+            })
+    }
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| !c.is_zero())
+            .collect::<Vec<(usize, &Rational)>>();
+
+        if terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (i, (power, c)) in terms.iter().enumerate() {
+            let sign = match (i, c.numerator < 0) {
+                (0, false) => "",
+                (0, true) => "-",
+                (_, false) => " + ",
+                (_, true) => " - ",
+            };
+
+            let numerator = c.numerator.unsigned_abs();
+            let variable = match power {
+                0 => String::new(),
+                1 => "x".to_string(),
+                _ => format!("x^{}", power),
+            };
+
+            // Leave out a 1 in front of x, but keep it if it's all there is
+            let numerator = if numerator == 1 && !variable.is_empty() {
+                String::new()
+            } else {
+                numerator.to_string()
+            };
+
+            write!(f, "{}{}{}", sign, numerator, variable)?;
+
+            if c.denominator != 1 {
+                write!(f, "/{}", c.denominator)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Newton's formula gives the polynomial as
+//
+//   f(x) = sum over j of  Δʲf(0) * x (x - 1) .. (x - j + 1) / j!
+//
+// so we multiply out each falling factorial and add up the coefficients.
+pub fn fit_polynomial(values: &[i64]) -> Result<Polynomial, ExtrapolationError> {
+    let degree = degree(values)?;
+    let differences = leading_differences(values).ok_or(ExtrapolationError::Overflow)?;
+
+    from_differences(&differences[..=degree])
+}
+
+// Builds the polynomial from Δ⁰f(0) up to the last non zero leading difference
+pub fn from_differences(differences: &[i128]) -> Result<Polynomial, ExtrapolationError> {
+    let mut coefficients = vec![Rational::integer(0); differences.len()];
+
+    // x (x - 1) .. (x - j + 1) and j!, starting from j = 0
+    let mut falling_factorial: Vec<i128> = vec![1];
+    let mut factorial: i128 = 1;
+
+    for (j, difference) in differences.iter().enumerate() {
+        if j > 0 {
+            falling_factorial = multiply_by_x_minus(&falling_factorial, j as i128 - 1)
+                .ok_or(ExtrapolationError::Overflow)?;
+            factorial = factorial
+                .checked_mul(j as i128)
+                .ok_or(ExtrapolationError::Overflow)?;
+        }
+
+        if *difference == 0 {
+            continue;
+        }
+
+        let scale = Rational::new(*difference, factorial).ok_or(ExtrapolationError::Overflow)?;
+
+        for (power, term) in falling_factorial.iter().enumerate() {
+            coefficients[power] = Rational::integer(*term)
+                .checked_mul(scale)
+                .and_then(|term| coefficients[power].checked_add(term))
+                .ok_or(ExtrapolationError::Overflow)?;
+        }
+    }
+
+    Ok(Polynomial { coefficients })
+}
+
+// Multiplies a polynomial (lowest power first) by (x - t)
+fn multiply_by_x_minus(polynomial: &[i128], t: i128) -> Option<Vec<i128>> {
+    let mut output = vec![0i128; polynomial.len() + 1];
+
+    for (power, c) in polynomial.iter().enumerate() {
+        output[power + 1] = output[power + 1].checked_add(*c)?;
+        output[power] = output[power].checked_sub(c.checked_mul(t)?)?;
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn rational_works() {
+        assert_eq!(rational(4, -6), rational(-2, 3));
+        assert_eq!(
+            rational(1, 2).checked_add(rational(1, 3)),
+            Some(rational(5, 6))
+        );
+        assert_eq!(
+            rational(2, 3).checked_mul(rational(3, 4)),
+            Some(rational(1, 2))
+        );
+        assert_eq!(Rational::new(1, 0), None);
+        assert_eq!(rational(-3, 6).to_string(), "-1/2");
+    }
+
+    #[test]
+    fn fit_polynomial_works() {
+        // Triangular numbers, (x + 1)(x + 2) / 2
+        let result = fit_polynomial(&[1, 3, 6, 10, 15, 21]).unwrap();
+        assert_eq!(
+            result.coefficients,
+            vec![rational(1, 1), rational(3, 2), rational(1, 2)]
+        );
+        assert_eq!(result.to_string(), "x^2/2 + 3x/2 + 1");
+        assert_eq!(result.degree(), 2);
+    }
+
+    #[test]
+    fn fit_polynomial_matches_examples() {
+        let result = fit_polynomial(&[0, 3, 6, 9, 12, 15]).unwrap();
+        assert_eq!(result.to_string(), "3x");
+
+        let result = fit_polynomial(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(result.to_string(), "x^3/3 - x^2 + 11x/3 + 10");
+
+        let result = fit_polynomial(&[-4, -4, -4]).unwrap();
+        assert_eq!(result.to_string(), "-4");
+
+        let result = fit_polynomial(&[0, 0]).unwrap();
+        assert_eq!(result.to_string(), "0");
+    }
+
+    #[test]
+    fn evaluate_matches_sequence() {
+        let values = [10, 13, 16, 21, 30, 45];
+        let polynomial = fit_polynomial(&values).unwrap();
+
+        for (x, value) in values.iter().enumerate() {
+            let result = polynomial.evaluate(Rational::integer(x as i128));
+            assert_eq!(result, Some(Rational::integer(*value as i128)));
+        }
+
+        // Next and previous values from the puzzle
+        assert_eq!(
+            polynomial.evaluate(Rational::integer(6)),
+            Some(Rational::integer(68))
+        );
+        assert_eq!(
+            polynomial.evaluate(Rational::integer(-1)),
+            Some(Rational::integer(5))
+        );
+        assert_eq!(polynomial.evaluate(rational(1, 2)), Some(rational(93, 8)));
+    }
+
+    #[test]
+    fn fit_polynomial_rejects_non_polynomials() {
+        let result = fit_polynomial(&[1, 2, 4, 8]);
+        assert_eq!(result, Err(ExtrapolationError::NotPolynomial { length: 4 }));
+    }
+}
@@ -0,0 +1,375 @@
+use std::fmt;
+use std::io::{self, BufRead, Bytes};
+
+use crate::{binomial, Direction, Extrapolation, ExtrapolationError};
+
+// Builds up the difference pyramid one value at a time, but only keeps the
+// two diagonals we need to predict from:
+//
+//   first[k] = first value of row k, used to predict backwards
+//   last[k]  = last value of row k, used to predict forwards
+//
+// When a value arrives the new last diagonal is the value followed by the
+// difference between each new and old entry:
+//
+//   10  13  16  21  30  (45)
+//     3   3   5   9  (15)
+//       0   2   4  (6)
+//         2   2  (2)
+//           0  (0)
+//
+// Rows below a trailing zero are all zero at the end too, so we don't store
+// them and `count` tells us how many rows there really are. For polynomial
+// sequences that keeps both diagonals at degree + 1 entries no matter how
+// many values we've seen.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreamingExtrapolator {
+    count: usize,
+    first: Vec<i128>,
+    last: Vec<i128>,
+}
+
+impl StreamingExtrapolator {
+    pub fn new() -> StreamingExtrapolator {
+        StreamingExtrapolator::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn push(&mut self, value: i64) -> Result<(), ExtrapolationError> {
+        let mut last = Vec::with_capacity(self.last.len() + 1);
+        last.push(i128::from(value));
+
+        for old in &self.last {
+            let previous = *last.last().expect("Should be a previous entry");
+            last.push(
+                previous
+                    .checked_sub(*old)
+                    .ok_or(ExtrapolationError::Overflow)?,
+            );
+        }
+
+        // Every row we haven't stored ended in zero, so the new entries all
+        // the way down to the single entry in the bottom row are the same
+        let tail = *last.last().expect("Should be a tail entry");
+        if tail != 0 && self.count > 0 {
+            last.resize(self.count + 1, tail);
+        }
+
+        self.count += 1;
+
+        // The bottom row only has one entry, so that's its first value too
+        let bottom = *last.get(self.count - 1).unwrap_or(&0);
+        if bottom != 0 {
+            self.first.resize(self.count - 1, 0);
+            self.first.push(bottom);
+        }
+
+        while last.last() == Some(&0) {
+            last.pop();
+        }
+
+        self.last = last;
+
+        Ok(())
+    }
+
+    // Same rules as `degree` in the lib, but from the diagonals we kept
+    pub fn degree(&self) -> Result<usize, ExtrapolationError> {
+        if self.count == 0 {
+            return Err(ExtrapolationError::Empty);
+        }
+
+        let degree = self.last.len().saturating_sub(1);
+
+        if !self.last.is_empty() && degree + 1 >= self.count {
+            return Err(ExtrapolationError::NotPolynomial { length: self.count });
+        }
+
+        Ok(degree)
+    }
+
+    // Going forward uses Newton's backward difference formula from the last
+    // value, and going back uses the forward one from the first value:
+    //
+    //   f(n - 1 + s) = sum over k of  ∇ᵏf(n - 1) * C(s + k - 1, k)
+    //   f(-s)        = sum over k of  Δᵏf(0) * C(-s, k)
+    pub fn predict(
+        &self,
+        direction: Direction,
+        steps: u64,
+    ) -> Result<Extrapolation, ExtrapolationError> {
+        let degree = self.degree()?;
+        let steps = i128::from(steps);
+
+        let terms = match direction {
+            Direction::Forward => self
+                .last
+                .iter()
+                .enumerate()
+                .map(|(k, difference)| {
+                    let x = (steps - 1).checked_add(k as i128)?;
+                    difference.checked_mul(binomial(x, k as u64)?)
+                })
+                .collect::<Option<Vec<i128>>>(),
+            Direction::Backward => self
+                .first
+                .iter()
+                .enumerate()
+                .map(|(k, difference)| difference.checked_mul(binomial(-steps, k as u64)?))
+                .collect::<Option<Vec<i128>>>(),
+        }
+        .ok_or(ExtrapolationError::Overflow)?;
+
+        let value = terms
+            .iter()
+            .try_fold(0i128, |total, term| total.checked_add(*term))
+            .ok_or(ExtrapolationError::Overflow)?;
+
+        Ok(Extrapolation { value, degree })
+    }
+}
+
+// -------- READING ---------
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    BadValue(String),
+    Extrapolation(ExtrapolationError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "Couldn't read input: {}", err),
+            StreamError::BadValue(value) => write!(f, "Invalid value {:?}", value),
+            StreamError::Extrapolation(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+// Reads one history per line, pushing values in as they are read so a line
+// never has to fit in memory
+pub struct HistoryReader<R: BufRead> {
+    bytes: Bytes<R>,
+    done: bool,
+}
+
+pub fn read_histories<R: BufRead>(reader: R) -> HistoryReader<R> {
+    HistoryReader {
+        bytes: reader.bytes(),
+        done: false,
+    }
+}
+
+impl<R: BufRead> HistoryReader<R> {
+    fn push_token(
+        extrapolator: &mut StreamingExtrapolator,
+        token: &mut String,
+    ) -> Result<(), StreamError> {
+        if token.is_empty() {
+            return Ok(());
+        }
+
+        let value = token
+            .parse::<i64>()
+            .map_err(|_| StreamError::BadValue(token.clone()))?;
+        token.clear();
+
+        extrapolator.push(value).map_err(StreamError::Extrapolation)
+    }
+}
+
+impl<R: BufRead> Iterator for HistoryReader<R> {
+    type Item = Result<StreamingExtrapolator, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut extrapolator = StreamingExtrapolator::new();
+        let mut token = String::new();
+
+        loop {
+            let byte = match self.bytes.next() {
+                Some(Ok(byte)) => byte,
+                Some(Err(err)) => {
+                    self.done = true;
+                    return Some(Err(StreamError::Io(err)));
+                }
+                None => {
+                    self.done = true;
+
+                    if let Err(err) = Self::push_token(&mut extrapolator, &mut token) {
+                        return Some(Err(err));
+                    }
+
+                    return if extrapolator.is_empty() {
+                        None
+                    } else {
+                        Some(Ok(extrapolator))
+                    };
+                }
+            };
+
+            match byte {
+                b'\n' => {
+                    if let Err(err) = Self::push_token(&mut extrapolator, &mut token) {
+                        return Some(Err(err));
+                    }
+
+                    // Skip blank lines
+                    if !extrapolator.is_empty() {
+                        return Some(Ok(extrapolator));
+                    }
+                }
+                b' ' | b'\t' | b'\r' => {
+                    if let Err(err) = Self::push_token(&mut extrapolator, &mut token) {
+                        return Some(Err(err));
+                    }
+                }
+                _ => token.push(byte as char),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extrapolate;
+
+    fn extrapolator_for(values: &[i64]) -> StreamingExtrapolator {
+        let mut extrapolator = StreamingExtrapolator::new();
+
+        for value in values {
+            extrapolator.push(*value).unwrap();
+        }
+
+        extrapolator
+    }
+
+    #[test]
+    fn push_keeps_only_the_diagonals() {
+        let extrapolator = extrapolator_for(&[10, 13, 16, 21, 30, 45]);
+
+        assert_eq!(extrapolator.len(), 6);
+        assert_eq!(extrapolator.last, vec![45, 15, 6, 2]);
+        assert_eq!(extrapolator.first, vec![10, 3, 0, 2]);
+    }
+
+    #[test]
+    fn predict_matches_examples() {
+        let extrapolator = extrapolator_for(&[10, 13, 16, 21, 30, 45]);
+
+        assert_eq!(
+            extrapolator.predict(Direction::Forward, 1),
+            Ok(Extrapolation {
+                value: 68,
+                degree: 3
+            })
+        );
+        assert_eq!(
+            extrapolator.predict(Direction::Backward, 1),
+            Ok(Extrapolation {
+                value: 5,
+                degree: 3
+            })
+        );
+    }
+
+    #[test]
+    fn predict_matches_closed_form() {
+        let sequences: [&[i64]; 5] = [
+            &[0, 3, 6, 9, 12, 15],
+            &[1, 3, 6, 10, 15, 21],
+            &[7, 7, 7],
+            &[0, 0, 0, 1, 4],
+            &[-5, 2, -1, 8, 40, 101, 197],
+        ];
+
+        for values in sequences {
+            let extrapolator = extrapolator_for(values);
+
+            for direction in [Direction::Forward, Direction::Backward] {
+                for steps in [1, 2, 10, 1_000_000] {
+                    assert_eq!(
+                        extrapolator.predict(direction, steps),
+                        extrapolate(values, direction, steps),
+                        "{:?} {:?} {}",
+                        values,
+                        direction,
+                        steps
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn memory_stays_at_degree() {
+        let mut extrapolator = StreamingExtrapolator::new();
+
+        for x in 0..1_000_000i64 {
+            extrapolator.push(3 * x * x - x + 7).unwrap();
+        }
+
+        assert_eq!(extrapolator.last.len(), 3);
+        assert_eq!(extrapolator.first.len(), 3);
+
+        let x = 1_000_000i128;
+        assert_eq!(
+            extrapolator.predict(Direction::Forward, 1),
+            Ok(Extrapolation {
+                value: 3 * x * x - x + 7,
+                degree: 2
+            })
+        );
+    }
+
+    #[test]
+    fn degree_rejects_sequences_that_are_not_polynomial() {
+        let extrapolator = extrapolator_for(&[1, 2, 4, 8, 16]);
+        assert_eq!(
+            extrapolator.predict(Direction::Forward, 1),
+            Err(ExtrapolationError::NotPolynomial { length: 5 })
+        );
+
+        let extrapolator = StreamingExtrapolator::new();
+        assert_eq!(extrapolator.degree(), Err(ExtrapolationError::Empty));
+    }
+
+    #[test]
+    fn read_histories_works() {
+        let input = "0 3 6 9 12 15\n\n1 3 6 10 15 21\r\n10 13 16 21 30 45";
+        let predictions = read_histories(input.as_bytes())
+            .map(|history| {
+                history
+                    .unwrap()
+                    .predict(Direction::Forward, 1)
+                    .unwrap()
+                    .value
+            })
+            .collect::<Vec<i128>>();
+
+        assert_eq!(predictions, vec![18, 28, 68]);
+    }
+
+    #[test]
+    fn read_histories_reports_bad_values() {
+        let mut histories = read_histories("1 2 x3".as_bytes());
+
+        match histories.next() {
+            Some(Err(StreamError::BadValue(value))) => assert_eq!(value, "x3"),
+            other => panic!("Expected a bad value, got {:?}", other),
+        }
+    }
+}
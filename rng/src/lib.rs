@@ -0,0 +1,59 @@
+// A small linear congruential generator, for generated inputs and randomised
+// tests. It isn't good randomness, but the same seed always gives the same
+// numbers, on any machine, without pulling in a crate for it.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    // Somewhere in 0..max
+    pub fn next(&mut self, max: u64) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.state >> 33) % max
+    }
+
+    // Somewhere in low..=high
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next(high - low + 1)
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.next(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.next(10)).collect::<Vec<u64>>()
+        };
+
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+        assert!(numbers(1).iter().all(|number| *number < 10));
+
+        let mut rng = Rng::new(3);
+        assert!((0..100).all(|_| (5..=7).contains(&rng.between(5, 7))));
+
+        let mut items = (0..20).collect::<Vec<u32>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<u32>>());
+    }
+}
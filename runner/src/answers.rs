@@ -0,0 +1,373 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::str::FromStr;
+
+// Which answer we mean: a part of a day, run on one of its inputs. The input
+// is named by its file, without the .txt.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+}
+
+impl Key {
+    pub fn new(day: u32, part: u32, input: &str) -> Key {
+        Key {
+            day,
+            part,
+            input: input.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} part {} {}", self.day, self.part, self.input)
+    }
+}
+
+// What the site said about an answer that wasn't right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    TooHigh,
+    TooLow,
+    // Without saying which way
+    Wrong,
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!(
+                "Invalid verdict {:?}, expected too-high, too-low or wrong",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+// Answers we know are right, so a change to a solution can be checked
+// against them, and answers we know are wrong, so they aren't submitted
+// again
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    pub confirmed: BTreeMap<Key, String>,
+    pub rejected: BTreeMap<Key, BTreeMap<String, Verdict>>,
+}
+
+impl Answers {
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.confirmed.get(key).map(|answer| answer.as_str())
+    }
+
+    // Replaces whatever was there before
+    pub fn confirm(&mut self, key: Key, answer: &str) {
+        self.confirmed.insert(key, answer.to_string());
+    }
+
+    pub fn reject(&mut self, key: Key, answer: &str, verdict: Verdict) {
+        self.rejected
+            .entry(key)
+            .or_default()
+            .insert(answer.to_string(), verdict);
+    }
+
+    // Why the answer can't be right, if we already know. Besides the answers
+    // we were told were wrong, a number past one that was too high or too
+    // low is wrong too.
+    pub fn known_wrong(&self, key: &Key, answer: &str) -> Option<String> {
+        if let Some(confirmed) = self.get(key) {
+            return (confirmed != answer).then(|| format!("{} is {}", key, confirmed));
+        }
+
+        let rejected = self.rejected.get(key)?;
+        if let Some(verdict) = rejected.get(answer) {
+            return Some(format!("{} was already {}", answer, verdict));
+        }
+
+        let number = answer.parse::<i64>().ok()?;
+
+        rejected.iter().find_map(|(wrong, verdict)| {
+            let wrong_number = wrong.parse::<i64>().ok()?;
+
+            match verdict {
+                Verdict::TooHigh if number >= wrong_number => {
+                    Some(format!("{} is too high, as {} was", answer, wrong))
+                }
+                Verdict::TooLow if number <= wrong_number => {
+                    Some(format!("{} is too low, as {} was", answer, wrong))
+                }
+                _ => None,
+            }
+        })
+    }
+}
+
+// One answer per line: the day, the part, the input and the answer, e.g.
+// "1 2 input 54265". An answer that was wrong has a verdict after it, e.g.
+// "1 2 input 54000 too-low". Blank lines and lines starting with # are
+// skipped.
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+
+        let lines = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+        for (number, line) in lines {
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+
+            let (day, part, input, answer, verdict) = match fields[..] {
+                [day, part, input, answer] => (day, part, input, answer, None),
+                [day, part, input, answer, verdict] => (
+                    day,
+                    part,
+                    input,
+                    answer,
+                    Some(
+                        verdict
+                            .parse::<Verdict>()
+                            .map_err(|err| format!("Line {}: {}", number, err))?,
+                    ),
+                ),
+                _ => {
+                    return Err(format!(
+                        "Line {}: expected a day, part, input and answer, found {:?}",
+                        number, line
+                    ))
+                }
+            };
+
+            let number_of = |name: &str, value: &str| {
+                value
+                    .parse::<u32>()
+                    .map_err(|_| format!("Line {}: invalid {} {:?}", number, name, value))
+            };
+
+            let key = Key::new(number_of("day", day)?, number_of("part", part)?, input);
+
+            let confirmed = answers.get(&key);
+            let rejected = answers
+                .rejected
+                .get(&key)
+                .and_then(|rejected| rejected.get(answer))
+                .copied();
+
+            let conflict = match (verdict, confirmed, rejected) {
+                (None, Some(confirmed), _) if confirmed != answer => {
+                    Some(format!("{} is both {} and {}", key, confirmed, answer))
+                }
+                (None, _, Some(_)) => {
+                    Some(format!("{} is both right and wrong for {}", answer, key))
+                }
+                (Some(_), Some(confirmed), _) if confirmed == answer => {
+                    Some(format!("{} is both right and wrong for {}", answer, key))
+                }
+                (Some(verdict), _, Some(existing)) if existing != verdict => Some(format!(
+                    "{} is both {} and {} for {}",
+                    answer, existing, verdict, key
+                )),
+                _ => None,
+            };
+
+            if let Some(conflict) = conflict {
+                return Err(format!("Line {}: {}", number, conflict));
+            }
+
+            match verdict {
+                None => answers.confirm(key, answer),
+                Some(verdict) => answers.reject(key, answer, verdict),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+// A line of the file for one answer, with the verdict if it was wrong
+pub fn line(key: &Key, answer: &str, verdict: Option<Verdict>) -> String {
+    match verdict {
+        Some(verdict) => format!(
+            "{} {} {} {} {}",
+            key.day, key.part, key.input, answer, verdict
+        ),
+        None => format!("{} {} {} {}", key.day, key.part, key.input, answer),
+    }
+}
+
+// The file with one more answer on the end. Everything already in it stays
+// as it was, comments included.
+pub fn append(file: &str, key: &Key, answer: &str, verdict: Option<Verdict>) -> String {
+    let mut file = file.to_string();
+
+    if !file.is_empty() && !file.ends_with('\n') {
+        file.push('\n');
+    }
+    file += &line(key, answer, verdict);
+    file.push('\n');
+
+    file
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part input answer")?;
+
+        let keys = self
+            .confirmed
+            .keys()
+            .chain(self.rejected.keys())
+            .collect::<BTreeSet<&Key>>();
+
+        for key in keys {
+            if let Some(answer) = self.get(key) {
+                writeln!(f, "{}", line(key, answer, None))?;
+            }
+
+            for (answer, verdict) in self.rejected.get(key).into_iter().flatten() {
+                writeln!(f, "{}", line(key, answer, Some(*verdict)))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        let answers = "# Day 1
+1 1 input 54450
+
+1 2 larger 281"
+            .parse::<Answers>()
+            .unwrap();
+
+        assert_eq!(answers.get(&Key::new(1, 1, "input")), Some("54450"));
+        assert_eq!(answers.get(&Key::new(1, 2, "larger")), Some("281"));
+        assert_eq!(answers.get(&Key::new(1, 2, "input")), None);
+
+        assert!("1 1 input".parse::<Answers>().is_err());
+        assert!("one 1 input 5".parse::<Answers>().is_err());
+        assert!("1 1 input 5\n1 1 input 6".parse::<Answers>().is_err());
+        assert!("1 1 input 5\n1 1 input 5".parse::<Answers>().is_ok());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let mut answers = Answers::default();
+        answers.confirm(Key::new(9, 2, "input"), "1062");
+        answers.confirm(Key::new(1, 1, "input"), "-4");
+
+        let written = answers.to_string();
+        assert_eq!(
+            written,
+            "# day part input answer\n1 1 input -4\n9 2 input 1062\n"
+        );
+        assert_eq!(written.parse::<Answers>(), Ok(answers));
+    }
+
+    #[test]
+    fn wrong_answers_work() {
+        let mut answers = "1 1 input 100 too-high
+1 1 input 50 too-low
+1 1 input 60 wrong
+1 1 input 60 wrong"
+            .parse::<Answers>()
+            .unwrap();
+        let key = Key::new(1, 1, "input");
+
+        assert_eq!(answers.get(&key), None);
+        assert_eq!(
+            answers.known_wrong(&key, "60"),
+            Some("60 was already wrong".to_string())
+        );
+        assert_eq!(
+            answers.known_wrong(&key, "120"),
+            Some("120 is too high, as 100 was".to_string())
+        );
+        assert_eq!(
+            answers.known_wrong(&key, "-3"),
+            Some("-3 is too low, as 50 was".to_string())
+        );
+        assert_eq!(answers.known_wrong(&key, "70"), None);
+        assert_eq!(answers.known_wrong(&key, "seventy"), None);
+        assert_eq!(answers.known_wrong(&Key::new(1, 2, "input"), "70"), None);
+
+        answers.confirm(key.clone(), "75");
+        assert_eq!(answers.known_wrong(&key, "75"), None);
+        assert_eq!(
+            answers.known_wrong(&key, "70"),
+            Some("day 1 part 1 input is 75".to_string())
+        );
+
+        let written = answers.to_string();
+        assert_eq!(
+            written,
+            "# day part input answer
+1 1 input 75
+1 1 input 100 too-high
+1 1 input 50 too-low
+1 1 input 60 wrong
+"
+        );
+        assert_eq!(written.parse::<Answers>(), Ok(answers));
+
+        assert!("1 1 input 5 too-big".parse::<Answers>().is_err());
+        assert!("1 1 input 5 too-high extra".parse::<Answers>().is_err());
+        assert!("1 1 input 5\n1 1 input 5 wrong".parse::<Answers>().is_err());
+        assert!("1 1 input 5 wrong\n1 1 input 5".parse::<Answers>().is_err());
+        assert!("1 1 input 5 wrong\n1 1 input 5 too-low"
+            .parse::<Answers>()
+            .is_err());
+    }
+
+    #[test]
+    fn append_keeps_comments() {
+        let file = "# Checked by hand\n1 1 input 54450\n\n# Still stuck on part 2";
+        let key = Key::new(1, 2, "input");
+
+        let appended = append(file, &key, "54000", Some(Verdict::TooLow));
+        assert_eq!(
+            appended,
+            "# Checked by hand\n1 1 input 54450\n\n# Still stuck on part 2\n1 2 input 54000 too-low\n"
+        );
+
+        let appended = append(&appended, &key, "54265", None);
+        assert!(appended.starts_with(file));
+        assert!(appended.ends_with("1 2 input 54000 too-low\n1 2 input 54265\n"));
+
+        let answers = appended.parse::<Answers>().unwrap();
+        assert_eq!(answers.get(&key), Some("54265"));
+        assert_eq!(
+            answers.known_wrong(&key, "54000"),
+            Some("day 1 part 2 input is 54265".to_string())
+        );
+
+        assert_eq!(append("", &key, "1", None), "1 2 input 1\n");
+    }
+}
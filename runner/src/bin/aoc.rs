@@ -0,0 +1,414 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use runner::answers::{self, Answers, Key, Verdict};
+use runner::client::Client;
+use runner::config::Config;
+use runner::days;
+use runner::examples;
+use runner::fetch::{self, Fetched};
+use runner::scaffold;
+use runner::submit::{self, Response};
+use runner::verify::{Check, Outcome, Verifier};
+
+// Jobs that cover every day.
+//
+// Usage: cargo run -p runner -- verify [--day N] [--timeout SECONDS]
+//                                      [--answers FILE] [--root DIR]
+//        cargo run -p runner -- examples [--day N] [--timeout SECONDS] [--root DIR]
+//        cargo run -p runner -- fetch --day N [--root DIR]
+//        cargo run -p runner -- submit --day N --part N [--answer ANSWER]
+//                                      [--timeout SECONDS] [--answers FILE] [--root DIR]
+//        cargo run -p runner -- new --day N [--root DIR]
+//
+// verify runs every part on every input in the answers file, and on the
+// input its binary uses, and says whether it still gets the confirmed
+// answer. Parts with no confirmed answer are reported as unknown. Exits with
+// 1 if anything fails.
+//
+// The answers file defaults to answers.txt in the root of the repository.
+// Each line is a day, a part, an input and its answer, e.g.
+// "1 2 input 54265". The input is the name of a .txt file in the
+// day's src/bin.
+//
+// examples runs every example in each day's examples directory: an input,
+// e.g. larger.txt, with the answers for it in larger.part-1 and
+// larger.part-2. cargo test runs them too, so adding an example is just
+// adding the files.
+//
+// fetch downloads a day's input to its src/bin/input.txt, unless it's
+// already there. It needs the session cookie from a logged in browser, in
+// AOC_SESSION or in aoc.conf in the root of the repository:
+//
+//   session = 5361...
+//   # These are optional
+//   base_url = https://adventofcode.com
+//   interval = 5
+//
+// interval is the least number of seconds to leave between requests, even
+// from separate runs, as the time of the last one is kept in
+// .aoc-last-request in the root of the repository.
+//
+// submit sends the answer to a part, which is whatever the part gets on the
+// day's input unless it's given, and says whether it was right. Right and
+// wrong answers are both recorded in the answers file, wrong ones with a
+// verdict after them, e.g. "1 2 input 54000 too-low", and an answer that's
+// known to be wrong isn't sent again. Answers are added to the end of the
+// file, leaving the rest of it, comments included, as it was.
+//
+// new sets up a day: its crate with parse and solve for each part, and the
+// binaries that run them, an empty src/bin/input.txt for fetch to fill in,
+// and an empty examples/example.txt with empty answers next to it. It's
+// added to the workspace, and to the runner so the jobs above cover it.
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Verify {
+        day: Option<u32>,
+        timeout: Duration,
+    },
+    Examples {
+        day: Option<u32>,
+        timeout: Duration,
+    },
+    Fetch {
+        day: u32,
+    },
+    Submit {
+        day: u32,
+        part: u32,
+        answer: Option<String>,
+        timeout: Duration,
+    },
+    New {
+        day: u32,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    command: Command,
+    root: PathBuf,
+    answers: PathBuf,
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    let verifier = Verifier {
+        root: args.root,
+        timeout: Duration::ZERO,
+    };
+
+    match args.command {
+        Command::Verify { day, timeout } => {
+            let answers = load_answers(&args.answers);
+            let solutions = solutions_for(day);
+            let verifier = Verifier {
+                timeout,
+                ..verifier
+            };
+
+            report(&verifier.verify(&solutions, &answers));
+        }
+        Command::Examples { day, timeout } => {
+            let examples = match examples::discover(&verifier.root) {
+                Ok(examples) => examples,
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+                    std::process::exit(1);
+                }
+            };
+            let examples = examples
+                .into_iter()
+                .filter(|example| day.is_none_or(|day| example.day == day))
+                .collect::<Vec<_>>();
+            let verifier = Verifier {
+                timeout,
+                ..verifier
+            };
+
+            report(&examples::run(&verifier, &days::solutions(), &examples));
+        }
+        Command::Fetch { day } => {
+            let fetched = Config::load(&verifier.root)
+                .and_then(Client::new)
+                .and_then(|mut client| fetch::fetch(&mut client, &verifier.root, day));
+
+            match fetched {
+                Ok(Fetched::Cached(path)) => println!("Result: {} (cached)", path.display()),
+                Ok(Fetched::Downloaded(path)) => println!("Result: {}", path.display()),
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            timeout,
+        } => {
+            let mut answers = load_answers(&args.answers);
+            let verifier = Verifier {
+                timeout,
+                ..verifier
+            };
+            let answer = answer.map(Ok).unwrap_or_else(|| {
+                let solutions = days::solutions();
+                let solution = days::find(&solutions, day, part).ok_or(format!(
+                    "There's no solution for day {} part {} yet",
+                    day, part
+                ))?;
+
+                verifier.run(solution, &fetch::input_path(&verifier.root, day))
+            });
+
+            let submitted = answer.and_then(|answer| {
+                let mut client = Config::load(&verifier.root).and_then(Client::new)?;
+                let response = submit::submit(&mut client, &mut answers, day, part, &answer)?;
+                Ok((answer, response))
+            });
+
+            match submitted {
+                Ok((answer, response)) => {
+                    let key = Key::new(day, part, "input");
+                    let recorded = match response {
+                        Response::Correct => record(&args.answers, &key, &answer, None),
+                        Response::Incorrect(verdict) => {
+                            record(&args.answers, &key, &answer, Some(verdict))
+                        }
+                        Response::Wait(_) | Response::WrongLevel => Ok(()),
+                    };
+
+                    if let Err(err) = recorded {
+                        eprintln!("Error: {}", err);
+                        std::process::exit(1);
+                    }
+
+                    println!("Result: {} is {}", answer, response);
+                }
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::New { day } => match scaffold::new_day(&verifier.root, day) {
+            Ok(written) => {
+                for path in written {
+                    println!("{}", path.display());
+                }
+                println!("Result: day {} is ready", day);
+            }
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+fn solutions_for(day: Option<u32>) -> Vec<days::Solution> {
+    days::solutions()
+        .into_iter()
+        .filter(|solution| day.is_none_or(|day| solution.day == day))
+        .collect()
+}
+
+fn report(checks: &[Check]) {
+    for check in checks {
+        println!("{}", check);
+    }
+
+    let count = |f: fn(&Outcome) -> bool| checks.iter().filter(|check| f(&check.outcome)).count();
+    let failed = count(|outcome| matches!(outcome, Outcome::Fail { .. }));
+
+    println!(
+        "Result: {} passed, {} failed, {} unknown",
+        count(|outcome| matches!(outcome, Outcome::Pass(_))),
+        failed,
+        count(|outcome| matches!(outcome, Outcome::Unknown(_)))
+    );
+
+    // Anything still running is stuck, so don't wait for it
+    std::process::exit(if failed > 0 { 1 } else { 0 });
+}
+
+// Only verify and submit need the answers, so a broken answers file doesn't
+// get in the way of anything else
+fn load_answers(path: &PathBuf) -> Answers {
+    match read_answers(path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+// No file just means nothing has been confirmed yet
+fn read_answers(path: &PathBuf) -> Result<Answers, String> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => contents
+            .parse::<Answers>()
+            .map_err(|err| format!("{}: {}", path.display(), err)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(err) => Err(format!("Couldn't read {}: {}", path.display(), err)),
+    }
+}
+
+// Adds the answer to the end of the file rather than writing it out again,
+// so nothing else in it changes
+fn record(path: &PathBuf, key: &Key, answer: &str, verdict: Option<Verdict>) -> Result<(), String> {
+    let file = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Answers::default().to_string(),
+        Err(err) => return Err(format!("Couldn't read {}: {}", path.display(), err)),
+    };
+
+    std::fs::write(path, answers::append(&file, key, answer, verdict))
+        .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.into_iter();
+    let command = args
+        .next()
+        .ok_or("Missing command, expected verify, examples, fetch, submit or new")?;
+
+    let mut day = None;
+    let mut part = None;
+    let mut answer = None;
+    let mut timeout = Duration::from_secs(10);
+    let mut root = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let mut answers = None;
+
+    fn number<T: std::str::FromStr>(arg: &str, value: String) -> Result<T, String> {
+        value
+            .parse()
+            .map_err(|_| format!("Invalid value for {}: {:?}", arg, value))
+    }
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+
+        match arg.as_str() {
+            "--day" => day = Some(number(&arg, value()?)?),
+            "--part" => part = Some(number(&arg, value()?)?),
+            "--answer" => answer = Some(value()?),
+            "--timeout" => {
+                let value = value()?;
+                timeout = value
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or(format!("Invalid value for {}: {:?}", arg, value))?
+            }
+            "--root" => root = PathBuf::from(value()?),
+            "--answers" => answers = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    let command = match command.as_str() {
+        "verify" => Command::Verify { day, timeout },
+        "examples" => Command::Examples { day, timeout },
+        "fetch" => Command::Fetch {
+            day: day.ok_or("Missing --day for fetch")?,
+        },
+        "submit" => Command::Submit {
+            day: day.ok_or("Missing --day for submit")?,
+            part: part.ok_or("Missing --part for submit")?,
+            answer,
+            timeout,
+        },
+        "new" => Command::New {
+            day: day.ok_or("Missing --day for new")?,
+        },
+        _ => return Err(format!("Unknown command: {}", command)),
+    };
+
+    Ok(Args {
+        command,
+        answers: answers.unwrap_or_else(|| root.join("answers.txt")),
+        root,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_args_works() {
+        assert_eq!(
+            parse_args(
+                ["verify", "--day", "3", "--timeout", "0.5", "--root", "repo"].map(String::from)
+            ),
+            Ok(Args {
+                command: Command::Verify {
+                    day: Some(3),
+                    timeout: Duration::from_millis(500),
+                },
+                root: PathBuf::from("repo"),
+                answers: PathBuf::from("repo/answers.txt"),
+            })
+        );
+
+        assert_eq!(
+            parse_args(["verify", "--answers", "mine.txt"].map(String::from))
+                .map(|args| args.answers),
+            Ok(PathBuf::from("mine.txt"))
+        );
+
+        assert_eq!(
+            parse_args(["examples", "--day", "8"].map(String::from)).map(|args| args.command),
+            Ok(Command::Examples {
+                day: Some(8),
+                timeout: Duration::from_secs(10),
+            })
+        );
+
+        assert_eq!(
+            parse_args(["fetch", "--day", "10"].map(String::from)).map(|args| args.command),
+            Ok(Command::Fetch { day: 10 })
+        );
+
+        assert!(parse_args(Vec::new()).is_err());
+        assert_eq!(
+            parse_args(
+                ["submit", "--day", "3", "--part", "2", "--answer", "467835"].map(String::from)
+            )
+            .map(|args| args.command),
+            Ok(Command::Submit {
+                day: 3,
+                part: 2,
+                answer: Some("467835".to_string()),
+                timeout: Duration::from_secs(10),
+            })
+        );
+
+        assert_eq!(
+            parse_args(["new", "--day", "11"].map(String::from)).map(|args| args.command),
+            Ok(Command::New { day: 11 })
+        );
+
+        assert!(parse_args(["fetch"].map(String::from)).is_err());
+        assert!(parse_args(["new"].map(String::from)).is_err());
+        assert!(parse_args(["submit", "--day", "3"].map(String::from)).is_err());
+        assert!(parse_args(["check"].map(String::from)).is_err());
+        assert!(parse_args(["verify", "--day", "x"].map(String::from)).is_err());
+        assert!(parse_args(["verify", "extra"].map(String::from)).is_err());
+        assert!(parse_args(["verify", "--timeout", "-1"].map(String::from)).is_err());
+        assert!(parse_args(["verify", "--timeout", "NaN"].map(String::from)).is_err());
+        assert!(parse_args(["verify", "--timeout", "inf"].map(String::from)).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+
+// Talks to the site for one run of the runner. Requests are spaced out by
+// the configured interval, however many we make, and from the last request
+// of an earlier run if the config says where that was saved.
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    // Everything we ask for is per user, so there's no point without a
+    // session
+    pub fn new(config: Config) -> Result<Client, String> {
+        if config.session.is_none() {
+            return Err(
+                "No session token, set AOC_SESSION or add session = ... to aoc.conf".to_string(),
+            );
+        }
+
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&config.user_agent)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        let last_request = config.last_request.as_ref().and_then(|path| {
+            let millis = std::fs::read_to_string(path)
+                .ok()?
+                .trim()
+                .parse::<u64>()
+                .ok()?;
+            let at = UNIX_EPOCH + Duration::from_millis(millis);
+            // A time in the future is left over from a clock change
+            let since = SystemTime::now().duration_since(at).ok()?;
+            Instant::now().checked_sub(since)
+        });
+
+        Ok(Client {
+            config,
+            agent,
+            last_request,
+        })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    // The path is under the year, e.g. "day/3/input"
+    pub fn get(&mut self, path: &str) -> Result<String, String> {
+        let request = self.request("GET", path);
+        Client::read(request.call())
+    }
+
+    // A form, e.g. an answer to "day/3/answer"
+    pub fn post(&mut self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let request = self.request("POST", path);
+        Client::read(request.send_form(form))
+    }
+
+    fn request(&mut self, method: &str, path: &str) -> ureq::Request {
+        if let Some(last_request) = self.last_request {
+            let waited = last_request.elapsed();
+            if waited < self.config.interval {
+                thread::sleep(self.config.interval - waited);
+            }
+        }
+        self.last_request = Some(Instant::now());
+
+        // Only there to be polite to the site, so a run that can't save it
+        // carries on
+        if let Some(path) = &self.config.last_request {
+            if let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) {
+                let _ = std::fs::write(path, now.as_millis().to_string());
+            }
+        }
+
+        let url = format!(
+            "{}/{}/{}",
+            self.config.base_url.trim_end_matches('/'),
+            self.config.year,
+            path
+        );
+        let session = self.config.session.as_deref().unwrap_or_default();
+
+        self.agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", session))
+    }
+
+    fn read(response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| format!("Couldn't read the response: {}", err)),
+            Err(ureq::Error::Status(status, response)) => {
+                let url = response.get_url().to_string();
+                let body = response.into_string().unwrap_or_default();
+                let reason = body.lines().next().unwrap_or_default().trim();
+
+                Err(format!("{} said {}: {}", url, status, reason))
+            }
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
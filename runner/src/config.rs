@@ -0,0 +1,151 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+// How to talk to the Advent of Code site. The session token is the value of
+// the session cookie from a logged in browser.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u32,
+    // The least time to leave between two requests
+    pub interval: Duration,
+    pub user_agent: String,
+    // Where the time of the last request is kept, so the interval holds
+    // across runs and not just within one
+    pub last_request: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            year: 2023,
+            interval: Duration::from_secs(5),
+            user_agent: "2023 Advent of Code runner".to_string(),
+            last_request: None,
+        }
+    }
+}
+
+impl Config {
+    // aoc.conf in the root of the repository, if there is one, then the
+    // environment on top of it
+    pub fn load(root: &Path) -> Result<Config, String> {
+        let path = root.join("aoc.conf");
+
+        let mut config = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .parse::<Config>()
+                .map_err(|err| format!("{}: {}", path.display(), err))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Config::default(),
+            Err(err) => return Err(format!("Couldn't read {}: {}", path.display(), err)),
+        };
+
+        config.apply_env(|name| std::env::var(name).ok());
+        config.last_request = Some(root.join(".aoc-last-request"));
+        Ok(config)
+    }
+
+    // AOC_SESSION and AOC_BASE_URL win over the file
+    pub fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) {
+        if let Some(session) = var("AOC_SESSION").filter(|session| !session.trim().is_empty()) {
+            self.session = Some(session.trim().to_string());
+        }
+        if let Some(base_url) = var("AOC_BASE_URL").filter(|url| !url.trim().is_empty()) {
+            self.base_url = base_url.trim().to_string();
+        }
+    }
+}
+
+// One setting per line, e.g. "session = 5361...". Anything left out keeps
+// its default. Blank lines and lines starting with # are skipped.
+impl FromStr for Config {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = Config::default();
+
+        let lines = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+        for (number, line) in lines {
+            let (name, value) = line
+                .split_once('=')
+                .map(|(name, value)| (name.trim(), value.trim().to_string()))
+                .ok_or(format!(
+                    "Line {}: expected a name = value, found {:?}",
+                    number, line
+                ))?;
+
+            let invalid = || format!("Line {}: invalid {} {:?}", number, name, value);
+
+            match name {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                "year" => config.year = value.parse().map_err(|_| invalid())?,
+                "interval" => {
+                    config.interval = value
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                        .ok_or_else(invalid)?
+                }
+                "user_agent" => config.user_agent = value,
+                _ => return Err(format!("Line {}: unknown setting {:?}", number, name)),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        let config = "# From the browser
+session = abc123
+
+base_url=http://localhost:8080
+interval = 0.5"
+            .parse::<Config>()
+            .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                session: Some("abc123".to_string()),
+                base_url: "http://localhost:8080".to_string(),
+                interval: Duration::from_millis(500),
+                ..Config::default()
+            }
+        );
+
+        assert!("session".parse::<Config>().is_err());
+        assert!("year = next".parse::<Config>().is_err());
+        assert!("interval = -1".parse::<Config>().is_err());
+        assert!("cookie = abc".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn env_wins() {
+        let mut config = "session = abc".parse::<Config>().unwrap();
+
+        config.apply_env(|name| match name {
+            "AOC_SESSION" => Some(" def\n".to_string()),
+            "AOC_BASE_URL" => Some("".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(config.session, Some("def".to_string()));
+        assert_eq!(config.base_url, Config::default().base_url);
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt::Display;
+
+// Turns whatever a part returns into the answer as it would be typed in,
+// or the error it gave up with
+pub trait Answer {
+    fn answer(self) -> Result<String, String>;
+}
+
+macro_rules! number_answer {
+    ($($number:ty),*) => {
+        $(impl Answer for $number {
+            fn answer(self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+number_answer!(u32, u64, i64);
+
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn answer(self) -> Result<String, String> {
+        self.map(|answer| answer.to_string())
+            .map_err(|err| err.to_string())
+    }
+}
+
+// A part of a day, and the input its binary runs it on
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    pub day: u32,
+    pub part: u32,
+    pub input: &'static str,
+    pub solve: fn(&str) -> Result<String, String>,
+}
+
+// Most parts are just parse then solve
+macro_rules! solution {
+    ($day:expr, $part:expr, $input:expr, $module:path) => {{
+        use $module as module;
+
+        Solution {
+            day: $day,
+            part: $part,
+            input: $input,
+            solve: |input| module::solve(&module::parse(input)).answer(),
+        }
+    }};
+}
+
+// Every day that has been solved. A new day needs adding here.
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(1, 1, "input", day_01::part1),
+        solution!(1, 2, "input", day_01::part2),
+        // Games, schematics and cards are checked when they're parsed
+        Solution {
+            day: 2,
+            part: 1,
+            input: "input",
+            solve: |input| {
+                day_02::part1::parse(input)
+                    .map(|games| day_02::part1::solve(&games))
+                    .answer()
+            },
+        },
+        Solution {
+            day: 2,
+            part: 2,
+            input: "input",
+            solve: |input| {
+                day_02::part2::parse(input)
+                    .and_then(|games| day_02::part2::solve(&games))
+                    .answer()
+            },
+        },
+        Solution {
+            day: 3,
+            part: 1,
+            input: "input",
+            solve: |input| {
+                day_03::part1::parse(input)
+                    .map_err(|err| err.to_string())
+                    .and_then(|schematic| {
+                        day_03::part1::solve(&schematic).map_err(|err| err.to_string())
+                    })
+                    .answer()
+            },
+        },
+        Solution {
+            day: 3,
+            part: 2,
+            input: "input",
+            solve: |input| {
+                day_03::part2::parse(input)
+                    .map_err(|err| err.to_string())
+                    .and_then(|schematic| {
+                        day_03::part2::solve(&schematic).map_err(|err| err.to_string())
+                    })
+                    .answer()
+            },
+        },
+        Solution {
+            day: 4,
+            part: 1,
+            input: "input",
+            solve: |input| {
+                day_04::part1::parse(input)
+                    .map(|cards| day_04::part1::solve(&cards))
+                    .answer()
+            },
+        },
+        Solution {
+            day: 4,
+            part: 2,
+            input: "input",
+            solve: |input| {
+                day_04::part2::parse(input)
+                    .and_then(|cards| day_04::part2::solve(&cards))
+                    .answer()
+            },
+        },
+        solution!(5, 1, "input", day_05::part1),
+        solution!(5, 2, "input", day_05::part2),
+        solution!(6, 1, "input", day_06::part1),
+        solution!(6, 2, "input", day_06::part2),
+        solution!(7, 1, "input", day_07::part1),
+        solution!(7, 2, "input", day_07::part2),
+        solution!(8, 1, "input", day_08::part1),
+        solution!(8, 2, "input", day_08::part2),
+        solution!(9, 1, "input", day_09::part1),
+        solution!(9, 2, "input", day_09::part2),
+    ]
+}
+
+pub fn find(solutions: &[Solution], day: u32, part: u32) -> Option<&Solution> {
+    solutions
+        .iter()
+        .find(|solution| solution.day == day && solution.part == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_works() {
+        assert_eq!(42u32.answer(), Ok("42".to_string()));
+        assert_eq!((-3i64).answer(), Ok("-3".to_string()));
+        assert_eq!(Ok::<u64, String>(7).answer(), Ok("7".to_string()));
+        assert_eq!(
+            Err::<u64, &str>("no digits").answer(),
+            Err("no digits".to_string())
+        );
+    }
+
+    #[test]
+    fn solutions_run() {
+        let solutions = solutions();
+        let day_02 = find(&solutions, 2, 1).unwrap();
+
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        assert_eq!((day_02.solve)(input), Ok("3".to_string()));
+
+        let day_04 = find(&solutions, 4, 2).unwrap();
+        assert!((day_04.solve)("Card 1 41 | 41").is_err());
+
+        assert!(find(&solutions, 10, 1).is_none());
+    }
+}
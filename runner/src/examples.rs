@@ -0,0 +1,254 @@
+use std::path::{Path, PathBuf};
+
+use crate::answers::Key;
+use crate::days::{self, Solution};
+use crate::verify::{Check, Outcome, Verifier};
+
+// The examples for a day live in its examples directory. Each one is an
+// input, e.g. examples/larger.txt, and the answer to each part it's an
+// example for, in examples/larger.part-1 and examples/larger.part-2. Only
+// the parts with an answer are run, so an example that's only for part 2
+// just leaves out the .part-1. An empty answer isn't known yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub day: u32,
+    pub name: String,
+    pub input: PathBuf,
+    // (part, answer)
+    pub answers: Vec<(u32, String)>,
+}
+
+impl Example {
+    pub fn key(&self, part: u32) -> Key {
+        Key::new(self.day, part, &format!("examples/{}", self.name))
+    }
+}
+
+// Every example of every day-NN directory under the root, in order
+pub fn discover(root: &Path) -> Result<Vec<Example>, String> {
+    let read_dir = |path: &Path| {
+        std::fs::read_dir(path)
+            .map_err(|err| format!("Couldn't read {}: {}", path.display(), err))
+            .map(|entries| {
+                let mut paths = entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .collect::<Vec<PathBuf>>();
+                paths.sort();
+                paths
+            })
+    };
+
+    let mut examples = Vec::new();
+
+    for dir in read_dir(root)? {
+        let day = match dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day-"))
+            .and_then(|day| day.parse::<u32>().ok())
+        {
+            Some(day) => day,
+            None => continue,
+        };
+
+        let examples_dir = dir.join("examples");
+        if !examples_dir.is_dir() {
+            continue;
+        }
+
+        for input in read_dir(&examples_dir)? {
+            if input.extension().and_then(|extension| extension.to_str()) != Some("txt") {
+                continue;
+            }
+
+            let name = input
+                .file_stem()
+                .and_then(|name| name.to_str())
+                .ok_or(format!("Invalid example name: {}", input.display()))?
+                .to_string();
+
+            let mut answers = Vec::new();
+
+            for part in [1, 2] {
+                let path = examples_dir.join(format!("{}.part-{}", name, part));
+
+                if path.exists() {
+                    let answer = std::fs::read_to_string(&path)
+                        .map_err(|err| format!("Couldn't read {}: {}", path.display(), err))?;
+                    answers.push((part, answer.trim().to_string()));
+                }
+            }
+
+            if answers.is_empty() {
+                return Err(format!(
+                    "Example {} has no answers, expected {}.part-1 or {}.part-2 next to it",
+                    input.display(),
+                    name,
+                    name
+                ));
+            }
+
+            examples.push(Example {
+                day,
+                name,
+                input,
+                answers,
+            });
+        }
+    }
+
+    Ok(examples)
+}
+
+// Runs each example on the parts it has answers for. An example for a day
+// we have no solution for yet is unknown.
+pub fn run(verifier: &Verifier, solutions: &[Solution], examples: &[Example]) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for example in examples {
+        for (part, expected) in &example.answers {
+            let outcome = match days::find(solutions, example.day, *part) {
+                Some(solution) => match verifier.run(solution, &example.input) {
+                    got if expected.is_empty() => Outcome::Unknown(got),
+                    Ok(got) if &got == expected => Outcome::Pass(got),
+                    got => Outcome::Fail {
+                        expected: expected.clone(),
+                        got,
+                    },
+                },
+                None => Outcome::Unknown(Err("there's no solution yet".to_string())),
+            };
+
+            checks.push(Check {
+                key: example.key(*part),
+                outcome,
+            });
+        }
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn verifier(root: PathBuf) -> Verifier {
+        Verifier {
+            root,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    // The harness itself: every example in the repository
+    #[test]
+    fn examples_pass() {
+        let root = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+        let examples = discover(&root).unwrap();
+
+        let failures = run(&verifier(root), &days::solutions(), &examples)
+            .into_iter()
+            .filter(|check| matches!(check.outcome, Outcome::Fail { .. }))
+            .map(|check| check.to_string())
+            .collect::<Vec<String>>();
+
+        assert!(!examples.is_empty());
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn discover_works() {
+        let root = std::env::temp_dir().join(format!("examples-{}", std::process::id()));
+        let examples_dir = root.join("day-03/examples");
+        std::fs::create_dir_all(&examples_dir).unwrap();
+        std::fs::create_dir_all(root.join("day-04")).unwrap();
+        std::fs::create_dir_all(root.join("target/examples")).unwrap();
+
+        for (file, contents) in [
+            ("small.txt", "1\n"),
+            ("small.part-1", "1\n"),
+            ("small.part-2", " 2 \n"),
+            ("large.txt", "1 2\n"),
+            ("large.part-2", "3"),
+            ("notes.md", "Not an example"),
+        ] {
+            std::fs::write(examples_dir.join(file), contents).unwrap();
+        }
+
+        let examples = discover(&root);
+
+        std::fs::write(examples_dir.join("empty.txt"), "").unwrap();
+        let missing = discover(&root);
+
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            examples,
+            Ok(vec![
+                Example {
+                    day: 3,
+                    name: "large".to_string(),
+                    input: examples_dir.join("large.txt"),
+                    answers: vec![(2, "3".to_string())],
+                },
+                Example {
+                    day: 3,
+                    name: "small".to_string(),
+                    input: examples_dir.join("small.txt"),
+                    answers: vec![(1, "1".to_string()), (2, "2".to_string())],
+                },
+            ])
+        );
+        assert!(missing.unwrap_err().contains("empty.txt has no answers"));
+    }
+
+    #[test]
+    fn run_works() {
+        let root = std::env::temp_dir().join(format!("examples-run-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("input.txt"), "abc").unwrap();
+
+        let example = |day: u32, answers: &[(u32, &str)]| Example {
+            day,
+            name: "example".to_string(),
+            input: root.join("input.txt"),
+            answers: answers
+                .iter()
+                .map(|(part, answer)| (*part, answer.to_string()))
+                .collect(),
+        };
+
+        let solutions = [Solution {
+            day: 1,
+            part: 1,
+            input: "input",
+            solve: |input| Ok(input.len().to_string()),
+        }];
+
+        let checks = run(
+            &verifier(root.clone()),
+            &solutions,
+            &[
+                example(1, &[(1, "3")]),
+                example(1, &[(1, "4")]),
+                example(1, &[(1, "")]),
+                example(2, &[(2, "1")]),
+            ],
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            checks
+                .iter()
+                .map(|check| check.to_string())
+                .collect::<Vec<String>>(),
+            vec![
+                "day 1 part 1 examples/example: pass (3)",
+                "day 1 part 1 examples/example: fail, expected 4 but got 3",
+                "day 1 part 1 examples/example: unknown (3)",
+                "day 2 part 2 examples/example: unknown, there's no solution yet",
+            ]
+        );
+    }
+}
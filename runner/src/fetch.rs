@@ -0,0 +1,45 @@
+use std::path::{Path, PathBuf};
+
+use crate::client::Client;
+
+// Where a day's input lives, and where its binaries include it from
+pub fn input_path(root: &Path, day: u32) -> PathBuf {
+    root.join(format!("day-{:02}", day))
+        .join("src/bin/input.txt")
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    // We already had it, so didn't ask again
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// Inputs never change, so each one is only downloaded once. An empty file is
+// a placeholder, not an input.
+pub fn fetch(client: &mut Client, root: &Path, day: u32) -> Result<Fetched, String> {
+    let path = input_path(root, day);
+
+    let day_dir = root.join(format!("day-{:02}", day));
+    if !day_dir.is_dir() {
+        return Err(format!(
+            "There's no {} to put the input in",
+            day_dir.display()
+        ));
+    }
+
+    if std::fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.get(&format!("day/{}/input", day))?;
+    if input.trim().is_empty() {
+        return Err(format!("The input for day {} was empty", day));
+    }
+
+    std::fs::create_dir_all(path.parent().expect("Input should be in a directory"))
+        .and_then(|_| std::fs::write(&path, input))
+        .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))?;
+
+    Ok(Fetched::Downloaded(path))
+}
@@ -0,0 +1,9 @@
+pub mod answers;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod examples;
+pub mod fetch;
+pub mod scaffold;
+pub mod submit;
+pub mod verify;
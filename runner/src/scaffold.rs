@@ -0,0 +1,311 @@
+use std::path::{Path, PathBuf};
+
+// Adds a day to the contents of a file that needs to know about it
+type Register = fn(&str, u32) -> Result<String, String>;
+
+// A new day's crate, ready to solve: parse and solve for each part, the
+// binaries that run them on the input, an empty input for fetch to fill in,
+// and an empty example for the examples harness. Returns every file it
+// wrote or changed.
+pub fn new_day(root: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day-{:02}", day);
+    let dir = root.join(&name);
+
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Check everything that needs registering first, so a failure doesn't
+    // leave half a day behind
+    let registrations: [(PathBuf, Register); 3] = [
+        (root.join("Cargo.toml"), add_member),
+        (root.join("runner/Cargo.toml"), add_dependency),
+        (root.join("runner/src/days.rs"), add_solutions),
+    ];
+    let registrations = registrations
+        .into_iter()
+        .map(|(path, register)| {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|err| format!("Couldn't read {}: {}", path.display(), err))?;
+            let registered =
+                register(&contents, day).map_err(|err| format!("{}: {}", path.display(), err))?;
+            Ok((path, registered))
+        })
+        .collect::<Result<Vec<(PathBuf, String)>, String>>()?;
+
+    let files = [
+        ("Cargo.toml", manifest(day)),
+        ("src/lib.rs", "pub mod part1;\npub mod part2;\n".to_string()),
+        ("src/part1.rs", part(1)),
+        ("src/part2.rs", part(2)),
+        ("src/bin/part-1.rs", binary(day, 1)),
+        ("src/bin/part-2.rs", binary(day, 2)),
+        ("src/bin/input.txt", String::new()),
+        ("examples/example.txt", String::new()),
+        ("examples/example.part-1", String::new()),
+        ("examples/example.part-2", String::new()),
+    ]
+    .map(|(file, contents)| (dir.join(file), contents));
+
+    let mut written = Vec::new();
+
+    for (path, contents) in files.into_iter().chain(registrations) {
+        std::fs::create_dir_all(path.parent().expect("Files should be in a directory"))
+            .and_then(|_| std::fs::write(&path, contents))
+            .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+// The binaries are named after the day, so they don't collide with every
+// other day's in the workspace's target directory
+fn manifest(day: u32) -> String {
+    format!(
+        r#"[package]
+name = "day-{:02}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+
+[[bin]]
+name = "day-{:02}-part-1"
+path = "src/bin/part-1.rs"
+
+[[bin]]
+name = "day-{:02}-part-2"
+path = "src/bin/part-2.rs"
+"#,
+        day, day, day
+    )
+}
+
+fn part(part: u32) -> String {
+    format!(
+        r#"pub fn parse(input: &str) -> Vec<&str> {{
+    input.lines().collect()
+}}
+
+// TODO: solve part {}
+pub fn solve(_lines: &[&str]) -> u32 {{
+    0
+}}
+"#,
+        part
+    )
+}
+
+fn binary(day: u32, part: u32) -> String {
+    format!(
+        r#"use day_{:02}::part{}::{{parse, solve}};
+
+fn main() {{
+    let input = include_str!("./input.txt");
+    let result = solve(&parse(input));
+    println!("Result: {{}}", result);
+}}
+"#,
+        day, part
+    )
+}
+
+// The workspace members are in order, so the day goes before the first one
+// that comes after it
+fn add_member(manifest: &str, day: u32) -> Result<String, String> {
+    let member = format!("\"day-{:02}\"", day);
+    let start = manifest
+        .find("members = [")
+        .ok_or("Couldn't find the workspace members")?;
+    let end = manifest[start..]
+        .find(']')
+        .map(|end| start + end)
+        .ok_or("Couldn't find the end of the workspace members")?;
+
+    let mut lines = manifest[start..end].lines().collect::<Vec<&str>>();
+    if lines
+        .iter()
+        .any(|line| line.trim().trim_end_matches(',') == member)
+    {
+        return Err(format!("{} is already a member", member));
+    }
+
+    let at = lines
+        .iter()
+        .skip(1)
+        .position(|line| line.trim().trim_end_matches(',') > member.as_str())
+        .map_or(lines.len(), |at| at + 1);
+    let line = format!("    {},", member);
+    lines.insert(at, &line);
+
+    Ok(format!(
+        "{}{}\n{}",
+        &manifest[..start],
+        lines.join("\n"),
+        &manifest[end..]
+    ))
+}
+
+// After the other days
+fn add_dependency(manifest: &str, day: u32) -> Result<String, String> {
+    let name = format!("day-{:02}", day);
+    let dependency = format!("{} = {{ path = \"../{}\" }}", name, name);
+
+    let mut lines = manifest.lines().collect::<Vec<&str>>();
+    if lines
+        .iter()
+        .any(|line| line.starts_with(&format!("{} ", name)))
+    {
+        return Err(format!("{} is already a dependency", name));
+    }
+
+    let at = lines
+        .iter()
+        .rposition(|line| line.starts_with("day-"))
+        .or_else(|| lines.iter().position(|line| *line == "[dependencies]"))
+        .ok_or("Couldn't find the dependencies")?;
+    lines.insert(at + 1, &dependency);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// At the end of solutions()
+fn add_solutions(days: &str, day: u32) -> Result<String, String> {
+    let start = days
+        .find("pub fn solutions()")
+        .ok_or("Couldn't find solutions()")?;
+    let end = days[start..]
+        .find("\n    ]\n}")
+        .map(|end| start + end)
+        .ok_or("Couldn't find the end of solutions()")?;
+
+    let solutions = (1..=2)
+        .map(|part| {
+            format!(
+                "\n        solution!({}, {}, \"input\", day_{:02}::part{}),",
+                day, part, day, part
+            )
+        })
+        .collect::<String>();
+
+    Ok(format!("{}{}{}", &days[..end], solutions, &days[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE: &str = r#"[workspace]
+resolver = "2"
+members = [
+    "bench",
+    "day-01",
+    "day-09",
+    "runner",
+]
+exclude = ["day-10"]
+"#;
+
+    const RUNNER: &str = r#"[package]
+name = "runner"
+
+[dependencies]
+day-01 = { path = "../day-01" }
+day-09 = { path = "../day-09" }
+ureq = "2"
+"#;
+
+    const DAYS: &str = r#"pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(1, 1, "input", day_01::part1),
+    ]
+}
+
+pub fn find() {}
+"#;
+
+    #[test]
+    fn add_member_works() {
+        assert_eq!(
+            add_member(WORKSPACE, 3),
+            Ok(WORKSPACE.replace("    \"day-09\",", "    \"day-03\",\n    \"day-09\","))
+        );
+        assert_eq!(
+            add_member(WORKSPACE, 11),
+            Ok(WORKSPACE.replace("    \"runner\",", "    \"day-11\",\n    \"runner\","))
+        );
+        assert!(add_member(WORKSPACE, 9).is_err());
+        assert!(add_member("[package]", 9).is_err());
+    }
+
+    #[test]
+    fn add_dependency_works() {
+        assert_eq!(
+            add_dependency(RUNNER, 11),
+            Ok(RUNNER.replace(
+                "day-09 = { path = \"../day-09\" }",
+                "day-09 = { path = \"../day-09\" }\nday-11 = { path = \"../day-11\" }"
+            ))
+        );
+        assert_eq!(
+            add_dependency("[dependencies]\n", 1),
+            Ok("[dependencies]\nday-01 = { path = \"../day-01\" }\n".to_string())
+        );
+        assert!(add_dependency(RUNNER, 1).is_err());
+    }
+
+    #[test]
+    fn add_solutions_works() {
+        assert_eq!(
+            add_solutions(DAYS, 2),
+            Ok(DAYS.replace(
+                "day_01::part1),",
+                "day_01::part1),
+        solution!(2, 1, \"input\", day_02::part1),
+        solution!(2, 2, \"input\", day_02::part2),"
+            ))
+        );
+        assert!(add_solutions("pub fn find() {}", 2).is_err());
+    }
+
+    #[test]
+    fn new_day_works() {
+        let root = std::env::temp_dir().join(format!("scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("runner/src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        std::fs::write(root.join("runner/Cargo.toml"), RUNNER).unwrap();
+        std::fs::write(root.join("runner/src/days.rs"), DAYS).unwrap();
+
+        let written = new_day(&root, 2);
+        let again = new_day(&root, 2);
+        let read = |file: &str| std::fs::read_to_string(root.join(file)).unwrap();
+        let (manifest, binary, part2, days) = (
+            read("day-02/Cargo.toml"),
+            read("day-02/src/bin/part-2.rs"),
+            read("day-02/src/part2.rs"),
+            read("runner/src/days.rs"),
+        );
+
+        // Nothing is written if the day can't be registered
+        std::fs::write(root.join("runner/src/days.rs"), "").unwrap();
+        let unregistered = new_day(&root, 3);
+        let day_03 = root.join("day-03").exists();
+
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(written.map(|written| written.len()), Ok(13));
+        assert!(again.unwrap_err().ends_with("day-02 already exists"));
+        assert!(manifest.contains("name = \"day-02-part-2\"\npath = \"src/bin/part-2.rs\""));
+        assert!(binary.starts_with("use day_02::part2::{parse, solve};"));
+        assert!(binary.contains("include_str!(\"./input.txt\")"));
+        assert!(part2
+            .contains("// TODO: solve part 2\npub fn solve(_lines: &[&str]) -> u32 {\n    0\n}"));
+        assert!(!part2.contains("todo!"));
+        assert!(days.contains("solution!(2, 2, \"input\", day_02::part2),"));
+        assert!(unregistered.is_err());
+        assert!(!day_03);
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::answers::{Answers, Key, Verdict};
+use crate::client::Client;
+
+// What the site said to an answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Response {
+    Correct,
+    Incorrect(Verdict),
+    // We answered too recently, and have to wait this long to try again
+    Wait(Duration),
+    // The part is already solved, or part 1 isn't yet
+    WrongLevel,
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::Incorrect(Verdict::TooHigh) => write!(f, "too high"),
+            Response::Incorrect(Verdict::TooLow) => write!(f, "too low"),
+            Response::Incorrect(Verdict::Wrong) => write!(f, "wrong"),
+            Response::Wait(wait) => write!(f, "wait {} seconds", wait.as_secs()),
+            Response::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+        }
+    }
+}
+
+// The page the site answers with. What it says is in its <article>, e.g.
+// "That's not the right answer; your answer is too high." or "You gave an
+// answer too recently; ... You have 1m 7s left to wait."
+impl FromStr for Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let article = match (s.find("<article"), s.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &s[start..end],
+            _ => s,
+        };
+        let text = strip_tags(article);
+
+        if text.contains("That's the right answer") {
+            Ok(Response::Correct)
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Ok(Response::Incorrect(Verdict::TooHigh))
+            } else if text.contains("too low") {
+                Ok(Response::Incorrect(Verdict::TooLow))
+            } else {
+                Ok(Response::Incorrect(Verdict::Wrong))
+            }
+        } else if text.contains("You gave an answer too recently") {
+            wait(&text)
+                .map(Response::Wait)
+                .ok_or(format!("Couldn't find how long to wait in {:?}", text))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(Response::WrongLevel)
+        } else {
+            Err(format!("Couldn't make sense of the response: {:?}", text))
+        }
+    }
+}
+
+// The text without the markup, with runs of whitespace squashed
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+// From "You have 1m 7s left to wait" or "You have 37s left to wait"
+fn wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = text[start..].find(" left to wait")? + start;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+
+            match unit {
+                "h" => Some(number * 60 * 60),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+// Sends the answer to a part, unless we already know it's wrong, and
+// records what the site said about it. Answers are always for the real
+// input.
+pub fn submit(
+    client: &mut Client,
+    answers: &mut Answers,
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> Result<Response, String> {
+    let key = Key::new(day, part, "input");
+
+    if answers.get(&key) == Some(answer) {
+        return Err(format!("Not submitting, {} is already {}", key, answer));
+    }
+    if let Some(reason) = answers.known_wrong(&key, answer) {
+        return Err(format!("Not submitting, {}", reason));
+    }
+
+    let page = client.post(
+        &format!("day/{}/answer", day),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let response = page.parse::<Response>()?;
+
+    match response {
+        Response::Correct => answers.confirm(key, answer),
+        Response::Incorrect(verdict) => answers.reject(key, answer, verdict),
+        Response::Wait(_) | Response::WrongLevel => (),
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><head><title>Day 1 - Advent of Code 2023</title></head>\n<body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn parse_works() {
+        assert_eq!(
+            page("That's the right answer!  You are <em>one gold star</em> closer to restoring snow operations. <a href=\"/2023/day/1#part2\">[Continue to Part Two]</a>").parse(),
+            Ok(Response::Correct)
+        );
+        assert_eq!(
+            page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>.  Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a>").parse(),
+            Ok(Response::Incorrect(Verdict::TooHigh))
+        );
+        assert_eq!(
+            page("That's not the right answer; your answer is too low.").parse(),
+            Ok(Response::Incorrect(Verdict::TooLow))
+        );
+        assert_eq!(
+            page("That's not the right answer.  If you're stuck, make sure you're using the full input data.").parse(),
+            Ok(Response::Incorrect(Verdict::Wrong))
+        );
+        assert_eq!(
+            page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>").parse(),
+            Ok(Response::Wait(Duration::from_secs(37)))
+        );
+        assert_eq!(
+            page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 7s left to wait.").parse(),
+            Ok(Response::Wait(Duration::from_secs(67)))
+        );
+        assert_eq!(
+            page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/1\">[Return to Day 1]</a>").parse(),
+            Ok(Response::WrongLevel)
+        );
+
+        assert!(page("Welcome!").parse::<Response>().is_err());
+        assert!(page("You gave an answer too recently.")
+            .parse::<Response>()
+            .is_err());
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!(Response::Correct.to_string(), "correct");
+        assert_eq!(Response::Incorrect(Verdict::TooLow).to_string(), "too low");
+        assert_eq!(
+            Response::Wait(Duration::from_secs(67)).to_string(),
+            "wait 67 seconds"
+        );
+    }
+}
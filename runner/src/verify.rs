@@ -0,0 +1,257 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::answers::{Answers, Key};
+use crate::days::{self, Solution};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    // Matches the confirmed answer
+    Pass(String),
+    // Doesn't match, or didn't give an answer at all
+    Fail {
+        expected: String,
+        got: Result<String, String>,
+    },
+    // There's nothing to check it against yet
+    Unknown(Result<String, String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub key: Key,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.outcome {
+            Outcome::Pass(answer) => write!(f, "{}: pass ({})", self.key, answer),
+            Outcome::Fail {
+                expected,
+                got: Ok(got),
+            } => write!(
+                f,
+                "{}: fail, expected {} but got {}",
+                self.key, expected, got
+            ),
+            Outcome::Fail {
+                expected,
+                got: Err(err),
+            } => write!(f, "{}: fail, expected {} but {}", self.key, expected, err),
+            Outcome::Unknown(Ok(got)) => write!(f, "{}: unknown ({})", self.key, got),
+            Outcome::Unknown(Err(err)) => write!(f, "{}: unknown, {}", self.key, err),
+        }
+    }
+}
+
+// Where the inputs are and how long to give each part
+#[derive(Debug, Clone, PartialEq)]
+pub struct Verifier {
+    pub root: PathBuf,
+    pub timeout: Duration,
+}
+
+pub fn input_path(root: &Path, key: &Key) -> PathBuf {
+    root.join(format!("day-{:02}", key.day))
+        .join("src/bin")
+        .join(format!("{}.txt", key.input))
+}
+
+impl Verifier {
+    // Every answer we know about, and every part on the input its binary
+    // uses, whether we know the answer to that or not
+    pub fn verify(&self, solutions: &[Solution], answers: &Answers) -> Vec<Check> {
+        let mut keys = answers
+            .confirmed
+            .keys()
+            .filter(|key| days::find(solutions, key.day, key.part).is_some())
+            .cloned()
+            .collect::<Vec<Key>>();
+
+        for solution in solutions {
+            let key = Key::new(solution.day, solution.part, solution.input);
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+
+        keys.sort();
+
+        keys.into_iter()
+            .map(|key| {
+                let solution = days::find(solutions, key.day, key.part)
+                    .expect("Should only check parts we have a solution for");
+                let got = self.run(solution, &input_path(&self.root, &key));
+
+                let outcome = match answers.get(&key) {
+                    Some(expected) if got.as_deref() == Ok(expected) => {
+                        Outcome::Pass(expected.to_string())
+                    }
+                    Some(expected) => Outcome::Fail {
+                        expected: expected.to_string(),
+                        got,
+                    },
+                    None => Outcome::Unknown(got),
+                };
+
+                Check { key, outcome }
+            })
+            .collect()
+    }
+
+    // Day 8 part 2 never finishes on the real input, so each part gets a
+    // thread and we stop waiting for it after the timeout. The thread keeps
+    // running until we exit.
+    pub fn run(&self, solution: &Solution, path: &Path) -> Result<String, String> {
+        let input = std::fs::read_to_string(path)
+            .map_err(|err| format!("couldn't read {}: {}", path.display(), err))?;
+
+        let (sender, receiver) = mpsc::channel();
+        let solve = solution.solve;
+
+        thread::spawn(move || {
+            let _ = sender.send(solve(&input));
+        });
+
+        match receiver.recv_timeout(self.timeout) {
+            Ok(answer) => answer,
+            Err(RecvTimeoutError::Timeout) => {
+                Err(format!("timed out after {}s", self.timeout.as_secs_f64()))
+            }
+            Err(RecvTimeoutError::Disconnected) => Err("panicked".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solution(day: u32, part: u32, solve: fn(&str) -> Result<String, String>) -> Solution {
+        Solution {
+            day,
+            part,
+            input: "input",
+            solve,
+        }
+    }
+
+    fn root(name: &str, inputs: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("verify-{}-{}", name, std::process::id()));
+        let bin = root.join("day-01/src/bin");
+        std::fs::create_dir_all(&bin).unwrap();
+
+        for (input, contents) in inputs {
+            std::fs::write(bin.join(format!("{}.txt", input)), contents).unwrap();
+        }
+
+        root
+    }
+
+    fn outcomes(checks: &[Check]) -> Vec<(String, Outcome)> {
+        checks
+            .iter()
+            .map(|check| (format!("{}", check.key), check.outcome.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn verify_works() {
+        let root = root("works", &[("input", "1 2 3"), ("example", "4")]);
+        let verifier = Verifier {
+            root: root.clone(),
+            timeout: Duration::from_secs(5),
+        };
+
+        let sum = |input: &str| {
+            Ok(input
+                .split_whitespace()
+                .map(|n| n.parse::<u32>().unwrap())
+                .sum::<u32>()
+                .to_string())
+        };
+        let solutions = [
+            solution(1, 1, sum),
+            solution(1, 2, |_| Err("no digits".to_string())),
+        ];
+
+        let answers = "1 1 input 6
+1 1 example 5
+1 1 missing 1
+1 2 input 1
+9 1 input 1"
+            .parse::<Answers>()
+            .unwrap();
+
+        let checks = verifier.verify(&solutions, &answers);
+        std::fs::remove_dir_all(root).unwrap();
+
+        assert_eq!(
+            outcomes(&checks),
+            vec![
+                (
+                    "day 1 part 1 example".to_string(),
+                    Outcome::Fail {
+                        expected: "5".to_string(),
+                        got: Ok("4".to_string())
+                    }
+                ),
+                (
+                    "day 1 part 1 input".to_string(),
+                    Outcome::Pass("6".to_string())
+                ),
+                (
+                    "day 1 part 1 missing".to_string(),
+                    Outcome::Fail {
+                        expected: "1".to_string(),
+                        got: Err(format!(
+                            "couldn't read {}: No such file or directory (os error 2)",
+                            input_path(&verifier.root, &Key::new(1, 1, "missing")).display()
+                        ))
+                    }
+                ),
+                (
+                    "day 1 part 2 input".to_string(),
+                    Outcome::Fail {
+                        expected: "1".to_string(),
+                        got: Err("no digits".to_string())
+                    }
+                ),
+            ]
+        );
+
+        assert_eq!(
+            checks[3].to_string(),
+            "day 1 part 2 input: fail, expected 1 but no digits"
+        );
+    }
+
+    #[test]
+    fn unknown_answers_are_still_run() {
+        let root = root("unknown", &[("input", "")]);
+        let verifier = Verifier {
+            root: root.clone(),
+            timeout: Duration::from_millis(100),
+        };
+
+        let solutions = [
+            solution(1, 1, |_| Ok("12".to_string())),
+            solution(1, 2, |_| loop {
+                thread::sleep(Duration::from_secs(1));
+            }),
+        ];
+
+        let checks = verifier.verify(&solutions, &Answers::default());
+        std::fs::remove_dir_all(root).unwrap();
+
+        assert_eq!(checks[0].to_string(), "day 1 part 1 input: unknown (12)");
+        assert_eq!(
+            checks[1].to_string(),
+            "day 1 part 2 input: unknown, timed out after 0.1s"
+        );
+    }
+}
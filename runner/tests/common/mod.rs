@@ -0,0 +1,125 @@
+// Each test file only uses some of this
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use runner::config::Config;
+
+// A request the server got
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+// Stands in for the site: answers each request with the next of the given
+// responses, and keeps every request so the test can look at them
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<(u16, &str)>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect::<Vec<(u16, String)>>();
+        let seen = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for ((status, body), stream) in responses.into_iter().zip(listener.incoming()) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut parts = line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_string();
+                let path = parts.next().unwrap_or_default().to_string();
+
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    match line.trim_end().split_once(':') {
+                        Some((name, value)) => {
+                            headers.push((name.trim().to_string(), value.trim().to_string()))
+                        }
+                        None => break,
+                    }
+                }
+
+                let length = headers
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .map_or(0, |(_, value)| value.parse::<usize>().unwrap());
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                seen.lock().unwrap().push(Request {
+                    method,
+                    path,
+                    headers,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        MockServer { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn config(&self) -> Config {
+        Config {
+            session: Some("abc123".to_string()),
+            base_url: self.url.clone(),
+            interval: Duration::ZERO,
+            ..Config::default()
+        }
+    }
+}
+
+// An empty stand-in for the repository, with the given days in it
+pub fn root(name: &str, days: &[u32]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+
+    for day in days {
+        std::fs::create_dir_all(root.join(format!("day-{:02}", day))).unwrap();
+    }
+
+    root
+}
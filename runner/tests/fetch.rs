@@ -0,0 +1,135 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::MockServer;
+use runner::client::Client;
+use runner::config::Config;
+use runner::fetch::{fetch, input_path, Fetched};
+
+#[test]
+fn downloads_the_input() {
+    let server = MockServer::start(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+    let root = common::root("fetch-downloads", &[1]);
+    let mut client = Client::new(server.config()).unwrap();
+
+    let fetched = fetch(&mut client, &root, 1);
+    let input = std::fs::read_to_string(input_path(&root, 1));
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(fetched, Ok(Fetched::Downloaded(input_path(&root, 1))));
+    assert_eq!(input.unwrap(), "1abc2\npqr3stu8vwx\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/1/input");
+    assert_eq!(requests[0].body, "");
+    assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+    assert_eq!(
+        requests[0].header("User-Agent"),
+        Some(Config::default().user_agent.as_str())
+    );
+}
+
+#[test]
+fn cached_inputs_are_not_downloaded_again() {
+    let server = MockServer::start(vec![(200, "new")]);
+    let root = common::root("fetch-cached", &[2, 3]);
+    let mut client = Client::new(server.config()).unwrap();
+
+    std::fs::create_dir_all(root.join("day-02/src/bin")).unwrap();
+    std::fs::write(input_path(&root, 2), "old").unwrap();
+    // A placeholder doesn't count
+    std::fs::create_dir_all(root.join("day-03/src/bin")).unwrap();
+    std::fs::write(input_path(&root, 3), "").unwrap();
+
+    let cached = fetch(&mut client, &root, 2);
+    let downloaded = fetch(&mut client, &root, 3);
+    let inputs = [2, 3].map(|day| std::fs::read_to_string(input_path(&root, day)).unwrap());
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(cached, Ok(Fetched::Cached(input_path(&root, 2))));
+    assert_eq!(downloaded, Ok(Fetched::Downloaded(input_path(&root, 3))));
+    assert_eq!(inputs, ["old", "new"]);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn requests_are_spaced_out() {
+    let server = MockServer::start(vec![(200, "1"), (200, "2"), (200, "3")]);
+    let root = common::root("fetch-spaced", &[1, 2, 3]);
+    let mut client = Client::new(Config {
+        interval: Duration::from_millis(200),
+        ..server.config()
+    })
+    .unwrap();
+
+    // The first request goes straight away, and each of the others waits
+    let start = Instant::now();
+    for day in 1..=3 {
+        fetch(&mut client, &root, day).unwrap();
+    }
+    let elapsed = start.elapsed();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(server.requests().len(), 3);
+    assert!(elapsed >= Duration::from_millis(400), "{:?}", elapsed);
+}
+
+#[test]
+fn requests_are_spaced_out_across_runs() {
+    let server = MockServer::start(vec![(200, "1"), (200, "2")]);
+    let root = common::root("fetch-spaced-runs", &[1, 2]);
+    let config = Config {
+        interval: Duration::from_millis(300),
+        last_request: Some(root.join(".aoc-last-request")),
+        ..server.config()
+    };
+
+    // A new client each time, like separate runs of the runner
+    let start = Instant::now();
+    for day in 1..=2 {
+        let mut client = Client::new(config.clone()).unwrap();
+        fetch(&mut client, &root, day).unwrap();
+    }
+    let elapsed = start.elapsed();
+    let saved = root.join(".aoc-last-request").exists();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(server.requests().len(), 2);
+    assert!(saved);
+    assert!(elapsed >= Duration::from_millis(300), "{:?}", elapsed);
+}
+
+#[test]
+fn errors_are_reported() {
+    let server = MockServer::start(vec![(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+    )]);
+    let root = common::root("fetch-errors", &[4]);
+    let mut client = Client::new(server.config()).unwrap();
+
+    let fetched = fetch(&mut client, &root, 4);
+    let missing_day = fetch(&mut client, &root, 5);
+    let written = input_path(&root, 4).exists();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(
+        fetched,
+        Err(format!(
+            "{}/2023/day/4/input said 400: Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            server.url
+        ))
+    );
+    assert!(missing_day.unwrap_err().contains("day-05"));
+    assert!(!written);
+    assert_eq!(server.requests().len(), 1);
+
+    assert!(Client::new(Config {
+        session: None,
+        ..server.config()
+    })
+    .is_err());
+}
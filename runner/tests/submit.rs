@@ -0,0 +1,113 @@
+mod common;
+
+use std::time::Duration;
+
+use common::MockServer;
+use runner::answers::{Answers, Key, Verdict};
+use runner::client::Client;
+use runner::submit::{submit, Response};
+
+fn page(article: &str) -> String {
+    format!(
+        "<html><body><main><article><p>{}</p></article></main></body></html>",
+        article
+    )
+}
+
+#[test]
+fn answers_are_posted_and_recorded() {
+    let too_high = page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.");
+    let right = page("That's the right answer!  You are <em>one gold star</em> closer to restoring snow operations.");
+    let server = MockServer::start(vec![(200, &too_high), (200, &right)]);
+    let mut client = Client::new(server.config()).unwrap();
+    let mut answers = Answers::default();
+
+    assert_eq!(
+        submit(&mut client, &mut answers, 3, 2, "900000000"),
+        Ok(Response::Incorrect(Verdict::TooHigh))
+    );
+    assert_eq!(
+        submit(&mut client, &mut answers, 3, 2, "82818007"),
+        Ok(Response::Correct)
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/3/answer");
+    assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+    assert_eq!(
+        requests[0].header("Content-Type"),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(requests[0].body, "level=2&answer=900000000");
+    assert_eq!(requests[1].body, "level=2&answer=82818007");
+
+    assert_eq!(
+        answers.to_string(),
+        "# day part input answer
+3 2 input 82818007
+3 2 input 900000000 too-high
+"
+    );
+}
+
+#[test]
+fn known_wrong_answers_are_not_sent() {
+    let server = MockServer::start(vec![]);
+    let mut client = Client::new(server.config()).unwrap();
+    let mut answers = "1 1 input 60000 too-high
+1 1 input 50000 too-low
+1 1 input 55555 wrong
+9 2 input 1062"
+        .parse::<Answers>()
+        .unwrap();
+    let before = answers.clone();
+
+    for (day, answer) in [
+        (1, "55555"),
+        (1, "60001"),
+        (1, "49999"),
+        (9, "1063"),
+        (9, "1062"),
+    ] {
+        let part = if day == 1 { 1 } else { 2 };
+        let submitted = submit(&mut client, &mut answers, day, part, answer);
+
+        assert!(
+            submitted
+                .as_ref()
+                .is_err_and(|err| err.starts_with("Not submitting")),
+            "{} was submitted: {:?}",
+            answer,
+            submitted
+        );
+    }
+
+    assert_eq!(answers, before);
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn waiting_is_not_recorded() {
+    let wait = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 7s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a>");
+    let solved =
+        page("You don't seem to be solving the right level.  Did you already complete it?");
+    let server = MockServer::start(vec![(200, &wait), (200, &solved), (200, "<html></html>")]);
+    let mut client = Client::new(server.config()).unwrap();
+    let mut answers = Answers::default();
+
+    assert_eq!(
+        submit(&mut client, &mut answers, 5, 1, "35"),
+        Ok(Response::Wait(Duration::from_secs(67)))
+    );
+    assert_eq!(
+        submit(&mut client, &mut answers, 5, 1, "35"),
+        Ok(Response::WrongLevel)
+    );
+    assert!(submit(&mut client, &mut answers, 5, 1, "35").is_err());
+
+    assert_eq!(answers, Answers::default());
+    assert_eq!(answers.get(&Key::new(5, 1, "input")), None);
+    assert_eq!(server.requests().len(), 3);
+}